@@ -0,0 +1,462 @@
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::talib::{self, check};
+use crate::{MaType, TaError};
+
+///
+/// A TA-Lib function looked up by name at runtime, through the abstract interface of the C
+/// library (`ta_abstract.h`), for callers which only know at runtime which indicator to compute.
+///
+/// Inputs, options and outputs are named after the TA-Lib parameters in snake case, without
+/// their `in`/`optIn`/`out` prefix: `MACD` takes a `real` input, `fast_period`, `slow_period` and
+/// `signal_period` options and returns `macd`, `macd_signal` and `macd_hist`. Functions working on
+/// prices take the `open`, `high`, `low`, `close`, `volume` and `open_interest` series they need.
+/// Names are compared ignoring case and underscores, `"FastPeriod"` is `"fast_period"`.
+///
+/// #Sample
+/// ```
+/// use std::collections::HashMap;
+/// use rust_ta_lib::{AbstractFunction, OptionValue};
+///
+/// let close_prices: Vec<f64> = (0..50).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let macd = AbstractFunction::by_name("MACD").unwrap();
+/// let inputs = HashMap::from([("real", close_prices)]);
+/// let options = HashMap::from([
+///     ("fast_period", OptionValue::from(12)),
+///     ("slow_period", OptionValue::from(26)),
+/// ]);
+/// let outputs = macd.call(&inputs, &options).unwrap();
+/// let signal = outputs.real("macd_signal").unwrap();
+/// println!("{} values starting at {}", signal.len(), outputs.begin);
+/// ```
+#[derive(Clone, Copy)]
+pub struct AbstractFunction {
+    pub(crate) info: &'static crate::TA_FuncInfo,
+}
+
+// The function and parameter tables of TA-Lib are static and never written to
+unsafe impl Send for AbstractFunction {}
+unsafe impl Sync for AbstractFunction {}
+
+impl AbstractFunction {
+    /// Looks up the function called `name` in TA-Lib (`"MACD"`, `"sma"`...), fails with
+    /// [`TaError::FuncNotFound`] if there is none.
+    pub fn by_name(name: &str) -> Result<AbstractFunction, TaError> {
+        let name = CString::new(name.to_ascii_uppercase()).map_err(|_| TaError::FuncNotFound)?;
+        let _lib = talib::handle()?;
+        let mut handle = ptr::null();
+        check(unsafe { crate::TA_GetFuncHandle(name.as_ptr(), &mut handle) })?;
+        let mut info = ptr::null();
+        check(unsafe { crate::TA_GetFuncInfo(handle, &mut info) })?;
+        Ok(AbstractFunction {
+            info: unsafe { &*info },
+        })
+    }
+
+    /// The TA-Lib name of the function, such as `"MACD"`.
+    pub fn name(&self) -> &'static str {
+        text(self.info.name)
+    }
+
+    /// The group of the function in TA-Lib, such as `"Momentum Indicators"`.
+    pub fn group(&self) -> &'static str {
+        text(self.info.group)
+    }
+
+    /// A short description of the function, such as `"Moving Average Convergence/Divergence"`.
+    pub fn hint(&self) -> &'static str {
+        text(self.info.hint)
+    }
+
+    /// Names of the series [`AbstractFunction::call`] expects.
+    pub fn input_names(&self) -> Result<Vec<String>, TaError> {
+        let mut names = Vec::new();
+        for index in 0..self.info.nbInput {
+            let input = self.input(index)?;
+            if input.type_ == crate::TA_InputParameterType_TA_Input_Price {
+                names.extend(price_series(input.flags).map(|(_, name)| name.to_string()));
+            } else {
+                names.push(snake_case(text(input.paramName)));
+            }
+        }
+        Ok(names)
+    }
+
+    /// Names of the options [`AbstractFunction::call`] accepts.
+    pub fn option_names(&self) -> Result<Vec<String>, TaError> {
+        (0..self.info.nbOptInput)
+            .map(|index| Ok(snake_case(text(self.option(index)?.paramName))))
+            .collect()
+    }
+
+    /// Names of the outputs [`AbstractFunction::call`] returns.
+    pub fn output_names(&self) -> Result<Vec<String>, TaError> {
+        (0..self.info.nbOutput)
+            .map(|index| Ok(snake_case(text(self.output(index)?.paramName))))
+            .collect()
+    }
+
+    /// Runs the function over `inputs`, series of the same length keyed by input name. Options
+    /// missing from `options` keep their TA-Lib default.
+    ///
+    /// A name the function does not know fails with [`TaError::BadParam`], a missing input with
+    /// [`TaError::InputNotAllInitialize`], and an integer option given a value which is not an
+    /// integer with [`TaError::InvalidParamHolderType`].
+    pub fn call<K, V, L, W>(
+        &self,
+        inputs: impl IntoIterator<Item = (K, V)>,
+        options: impl IntoIterator<Item = (L, W)>,
+    ) -> Result<AbstractOutputs, TaError>
+    where
+        K: AsRef<str>,
+        V: AsRef<[f64]>,
+        L: AsRef<str>,
+        W: Into<OptionValue>,
+    {
+        let mut inputs: Vec<(String, V, bool)> = inputs
+            .into_iter()
+            .map(|(name, series)| (key(name.as_ref()), series, false))
+            .collect();
+        let mut take = |name: &str| -> Result<*const f64, TaError> {
+            let name = key(name);
+            match inputs.iter_mut().find(|(input, ..)| *input == name) {
+                Some((_, series, used)) => {
+                    *used = true;
+                    Ok(series.as_ref().as_ptr())
+                }
+                None => Err(TaError::InputNotAllInitialize),
+            }
+        };
+
+        let _lib = talib::handle()?;
+        let params = ParamHolder::alloc(self.info.handle)?;
+        for index in 0..self.info.nbInput {
+            let input = self.input(index)?;
+            match input.type_ {
+                crate::TA_InputParameterType_TA_Input_Price => {
+                    let mut prices = [ptr::null(); 6];
+                    for (slot, name) in price_series(input.flags) {
+                        prices[slot] = take(name)?;
+                    }
+                    let [open, high, low, close, volume, open_interest] = prices;
+                    check(unsafe {
+                        crate::TA_SetInputParamPricePtr(
+                            params.0,
+                            index,
+                            open,
+                            high,
+                            low,
+                            close,
+                            volume,
+                            open_interest,
+                        )
+                    })?;
+                }
+                crate::TA_InputParameterType_TA_Input_Real => {
+                    let series = take(&snake_case(text(input.paramName)))?;
+                    check(unsafe { crate::TA_SetInputParamRealPtr(params.0, index, series) })?;
+                }
+                _ => return Err(TaError::NotSupported),
+            }
+        }
+        if inputs.iter().any(|(.., used)| !used) {
+            return Err(TaError::BadParam);
+        }
+        let lens: Vec<usize> = inputs
+            .iter()
+            .map(|(_, series, _)| series.as_ref().len())
+            .collect();
+        // every TA-Lib function has an input, so this only skips the check for a broken table
+        let len = if lens.is_empty() {
+            0
+        } else {
+            crate::wrapper::input_len(&lens)?
+        };
+
+        for (name, value) in options {
+            let name = key(name.as_ref());
+            let index = (0..self.info.nbOptInput)
+                .find(|index| {
+                    self.option(*index)
+                        .is_ok_and(|option| key(&snake_case(text(option.paramName))) == name)
+                })
+                .ok_or(TaError::BadParam)?;
+            match self.option(index)?.type_ {
+                crate::TA_OptInputParameterType_TA_OptInput_RealRange
+                | crate::TA_OptInputParameterType_TA_OptInput_RealList => {
+                    let value = value.into().real();
+                    check(unsafe { crate::TA_SetOptInputParamReal(params.0, index, value) })?;
+                }
+                _ => {
+                    let value = value.into().integer()?;
+                    check(unsafe { crate::TA_SetOptInputParamInteger(params.0, index, value) })?;
+                }
+            }
+        }
+
+        let mut outputs = Vec::new();
+        for index in 0..self.info.nbOutput {
+            let output = self.output(index)?;
+            let mut values = match output.type_ {
+                crate::TA_OutputParameterType_TA_Output_Real => {
+                    AbstractValues::Real(Vec::with_capacity(len))
+                }
+                _ => AbstractValues::Integer(Vec::with_capacity(len)),
+            };
+            check(unsafe {
+                match &mut values {
+                    AbstractValues::Real(out) => {
+                        crate::TA_SetOutputParamRealPtr(params.0, index, out.as_mut_ptr())
+                    }
+                    AbstractValues::Integer(out) => {
+                        crate::TA_SetOutputParamIntegerPtr(params.0, index, out.as_mut_ptr())
+                    }
+                }
+            })?;
+            outputs.push((snake_case(text(output.paramName)), values));
+        }
+
+        let mut out_begin: crate::TA_Integer = 0;
+        let mut out_size: crate::TA_Integer = 0;
+        if len > 0 {
+            let end =
+                crate::TA_Integer::try_from(len - 1).map_err(|_| TaError::OutOfRangeEndIndex)?;
+            check(unsafe { crate::TA_CallFunc(params.0, 0, end, &mut out_begin, &mut out_size) })?;
+        }
+        // TA-lib wrote out_size values (never more than len) in each buffer
+        for (_, values) in outputs.iter_mut() {
+            match values {
+                AbstractValues::Real(out) => unsafe { out.set_len(out_size as usize) },
+                AbstractValues::Integer(out) => unsafe { out.set_len(out_size as usize) },
+            }
+        }
+
+        Ok(AbstractOutputs {
+            values: outputs.into_iter().collect(),
+            begin: out_begin,
+        })
+    }
+
+    pub(crate) fn input(
+        &self,
+        index: u32,
+    ) -> Result<&'static crate::TA_InputParameterInfo, TaError> {
+        let mut info = ptr::null();
+        check(unsafe { crate::TA_GetInputParameterInfo(self.info.handle, index, &mut info) })?;
+        Ok(unsafe { &*info })
+    }
+
+    pub(crate) fn option(
+        &self,
+        index: u32,
+    ) -> Result<&'static crate::TA_OptInputParameterInfo, TaError> {
+        let mut info = ptr::null();
+        check(unsafe { crate::TA_GetOptInputParameterInfo(self.info.handle, index, &mut info) })?;
+        Ok(unsafe { &*info })
+    }
+
+    pub(crate) fn output(
+        &self,
+        index: u32,
+    ) -> Result<&'static crate::TA_OutputParameterInfo, TaError> {
+        let mut info = ptr::null();
+        check(unsafe { crate::TA_GetOutputParameterInfo(self.info.handle, index, &mut info) })?;
+        Ok(unsafe { &*info })
+    }
+}
+
+impl fmt::Debug for AbstractFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbstractFunction")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+/// Value of an option of an [`AbstractFunction`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptionValue {
+    /// Periods, MA types and other integer options
+    Integer(i64),
+    /// Deviations, factors and other real options
+    Real(f64),
+}
+
+impl OptionValue {
+    fn real(self) -> f64 {
+        match self {
+            OptionValue::Integer(value) => value as f64,
+            OptionValue::Real(value) => value,
+        }
+    }
+
+    // A real option read from a configuration may hold an integer such as 14.0
+    fn integer(self) -> Result<crate::TA_Integer, TaError> {
+        let value = match self {
+            OptionValue::Integer(value) => value,
+            OptionValue::Real(value) if value.fract() == 0.0 => value as i64,
+            OptionValue::Real(_) => return Err(TaError::InvalidParamHolderType),
+        };
+        value.try_into().map_err(|_| TaError::BadParam)
+    }
+}
+
+impl From<i32> for OptionValue {
+    fn from(value: i32) -> Self {
+        OptionValue::Integer(value.into())
+    }
+}
+
+impl From<u32> for OptionValue {
+    fn from(value: u32) -> Self {
+        OptionValue::Integer(value.into())
+    }
+}
+
+impl From<i64> for OptionValue {
+    fn from(value: i64) -> Self {
+        OptionValue::Integer(value)
+    }
+}
+
+impl From<f64> for OptionValue {
+    fn from(value: f64) -> Self {
+        OptionValue::Real(value)
+    }
+}
+
+impl From<MaType> for OptionValue {
+    fn from(ma_type: MaType) -> Self {
+        OptionValue::Integer(crate::TA_MAType::from(ma_type).into())
+    }
+}
+
+/// Lets maps of options be passed by reference.
+impl<T: Copy + Into<OptionValue>> From<&T> for OptionValue {
+    fn from(value: &T) -> Self {
+        (*value).into()
+    }
+}
+
+/// Values of one output of an [`AbstractFunction`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbstractValues {
+    Real(Vec<crate::TA_Real>),
+    Integer(Vec<crate::TA_Integer>),
+}
+
+/// Output of [`AbstractFunction::call`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbstractOutputs {
+    /// Values of every output, by output name
+    pub values: BTreeMap<String, AbstractValues>,
+    /// Index of the input of the first value
+    pub begin: crate::TA_Integer,
+}
+
+impl AbstractOutputs {
+    /// The output called `name` if it holds real values.
+    pub fn real(&self, name: &str) -> Option<&[crate::TA_Real]> {
+        match self.get(name)? {
+            AbstractValues::Real(values) => Some(values),
+            AbstractValues::Integer(_) => None,
+        }
+    }
+
+    /// The output called `name` if it holds integer values, such as candlestick signals.
+    pub fn integer(&self, name: &str) -> Option<&[crate::TA_Integer]> {
+        match self.get(name)? {
+            AbstractValues::Integer(values) => Some(values),
+            AbstractValues::Real(_) => None,
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&AbstractValues> {
+        let name = key(name);
+        self.values
+            .iter()
+            .find(|(output, _)| key(output) == name)
+            .map(|(_, values)| values)
+    }
+}
+
+// Frees the parameters of one call, even if setting them up failed
+struct ParamHolder(*mut crate::TA_ParamHolder);
+
+impl ParamHolder {
+    fn alloc(handle: *const crate::TA_FuncHandle) -> Result<ParamHolder, TaError> {
+        let mut params = ptr::null_mut();
+        check(unsafe { crate::TA_ParamHolderAlloc(handle, &mut params) })?;
+        Ok(ParamHolder(params))
+    }
+}
+
+impl Drop for ParamHolder {
+    fn drop(&mut self) {
+        unsafe { crate::TA_ParamHolderFree(self.0) };
+    }
+}
+
+// Series of a price input, in the order of the arguments of TA_SetInputParamPricePtr, with a
+// pointer slot index
+pub(crate) fn price_series(
+    flags: crate::TA_InputFlags,
+) -> impl Iterator<Item = (usize, &'static str)> {
+    [
+        (crate::TA_IN_PRICE_OPEN, "open"),
+        (crate::TA_IN_PRICE_HIGH, "high"),
+        (crate::TA_IN_PRICE_LOW, "low"),
+        (crate::TA_IN_PRICE_CLOSE, "close"),
+        (crate::TA_IN_PRICE_VOLUME, "volume"),
+        (crate::TA_IN_PRICE_OPENINTEREST, "open_interest"),
+    ]
+    .into_iter()
+    .enumerate()
+    .filter(move |(_, (flag, _))| flags as u32 & flag != 0)
+    .map(|(slot, (_, name))| (slot, name))
+}
+
+pub(crate) fn text(text: *const c_char) -> &'static str {
+    if text.is_null() {
+        return "";
+    }
+    unsafe { CStr::from_ptr(text) }.to_str().unwrap_or("")
+}
+
+// "optInSlowK_MAType" -> "slow_k_ma_type", "outMACDSignal" -> "macd_signal"
+pub(crate) fn snake_case(param_name: &str) -> String {
+    let name = ["optIn", "in", "out"]
+        .iter()
+        .find_map(|prefix| param_name.strip_prefix(prefix))
+        .unwrap_or(param_name);
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+// Names are compared without case nor underscores
+fn key(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
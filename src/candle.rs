@@ -0,0 +1,58 @@
+///
+/// Output of the candlestick pattern recognition functions (`wrapper::cdl*`).
+///
+/// TA-Lib reports patterns as `TA_Integer` values: 0 when the pattern is not found, a positive
+/// value for a bullish pattern and a negative one for a bearish pattern. The magnitude is the
+/// strength of the signal, usually 100, 200 for patterns confirmed by a later candle (Hikkake).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CandleSignal {
+    /// The pattern is not present on this candle
+    #[default]
+    None,
+    /// Bullish pattern with its strength
+    Bullish(u32),
+    /// Bearish pattern with its strength
+    Bearish(u32),
+}
+
+impl CandleSignal {
+    /// Converts the raw value written by TA-Lib.
+    pub fn from_raw(value: crate::TA_Integer) -> CandleSignal {
+        match value {
+            0 => CandleSignal::None,
+            v if v > 0 => CandleSignal::Bullish(v.unsigned_abs()),
+            v => CandleSignal::Bearish(v.unsigned_abs()),
+        }
+    }
+
+    /// The raw TA-Lib value, -100/0/100 for most patterns.
+    pub fn raw(&self) -> crate::TA_Integer {
+        match *self {
+            CandleSignal::None => 0,
+            CandleSignal::Bullish(strength) => strength as crate::TA_Integer,
+            CandleSignal::Bearish(strength) => -(strength as crate::TA_Integer),
+        }
+    }
+
+    /// Whether a bullish pattern was found.
+    pub fn is_bullish(&self) -> bool {
+        matches!(self, CandleSignal::Bullish(_))
+    }
+
+    /// Whether a bearish pattern was found.
+    pub fn is_bearish(&self) -> bool {
+        matches!(self, CandleSignal::Bearish(_))
+    }
+
+    /// Whether a pattern, bullish or bearish, was found.
+    pub fn is_some(&self) -> bool {
+        !matches!(self, CandleSignal::None)
+    }
+}
+
+impl From<CandleSignal> for crate::TA_Integer {
+    fn from(signal: CandleSignal) -> Self {
+        signal.raw()
+    }
+}
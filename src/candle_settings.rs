@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+
+use crate::talib;
+use crate::TaError;
+
+///
+/// Setting used by the candlestick pattern functions, the `TA_CandleSettingType` of TA-Lib.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CandleSettingType {
+    /// Real body is long when it's longer than the average of the previous candles' bodies
+    BodyLong,
+    /// Real body is very long when it's longer than 3 times the average of the previous bodies
+    BodyVeryLong,
+    /// Real body is short when it's shorter than the average of the previous candles' bodies
+    BodyShort,
+    /// Real body is like doji's body when it's shorter than 10% the average of the high-low range
+    BodyDoji,
+    /// Shadow is long when it's longer than the real body
+    ShadowLong,
+    /// Shadow is very long when it's longer than 2 times the real body
+    ShadowVeryLong,
+    /// Shadow is short when it's shorter than half the average of the sum of the shadows
+    ShadowShort,
+    /// Shadow is very short when it's shorter than 10% the average of the high-low range
+    ShadowVeryShort,
+    /// When measuring distance between parts of candles or width of gaps, "near" means
+    /// "<= 20% of the average of the high-low range"
+    Near,
+    /// "far" means ">= 60% of the average of the high-low range"
+    Far,
+    /// "equal" means "<= 5% of the average of the high-low range"
+    Equal,
+}
+
+impl CandleSettingType {
+    /// Every candle setting, in the order of the C enum.
+    pub const ALL: [CandleSettingType; 11] = [
+        CandleSettingType::BodyLong,
+        CandleSettingType::BodyVeryLong,
+        CandleSettingType::BodyShort,
+        CandleSettingType::BodyDoji,
+        CandleSettingType::ShadowLong,
+        CandleSettingType::ShadowVeryLong,
+        CandleSettingType::ShadowShort,
+        CandleSettingType::ShadowVeryShort,
+        CandleSettingType::Near,
+        CandleSettingType::Far,
+        CandleSettingType::Equal,
+    ];
+
+    /// The `TA_CandleSettingType` of the setting.
+    pub fn id(&self) -> crate::TA_CandleSettingType {
+        match self {
+            CandleSettingType::BodyLong => crate::TA_CandleSettingType_TA_BodyLong,
+            CandleSettingType::BodyVeryLong => crate::TA_CandleSettingType_TA_BodyVeryLong,
+            CandleSettingType::BodyShort => crate::TA_CandleSettingType_TA_BodyShort,
+            CandleSettingType::BodyDoji => crate::TA_CandleSettingType_TA_BodyDoji,
+            CandleSettingType::ShadowLong => crate::TA_CandleSettingType_TA_ShadowLong,
+            CandleSettingType::ShadowVeryLong => crate::TA_CandleSettingType_TA_ShadowVeryLong,
+            CandleSettingType::ShadowShort => crate::TA_CandleSettingType_TA_ShadowShort,
+            CandleSettingType::ShadowVeryShort => crate::TA_CandleSettingType_TA_ShadowVeryShort,
+            CandleSettingType::Near => crate::TA_CandleSettingType_TA_Near,
+            CandleSettingType::Far => crate::TA_CandleSettingType_TA_Far,
+            CandleSettingType::Equal => crate::TA_CandleSettingType_TA_Equal,
+        }
+    }
+}
+
+///
+/// Part of the candles a setting is measured against, the `TA_RangeType` of TA-Lib.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeType {
+    /// Length of the real body (`|close - open|`)
+    RealBody,
+    /// Length of the whole candle (`high - low`)
+    HighLow,
+    /// Length of the shadows, half of `high - low - |close - open|`
+    Shadows,
+}
+
+impl From<RangeType> for crate::TA_RangeType {
+    fn from(range_type: RangeType) -> Self {
+        match range_type {
+            RangeType::RealBody => crate::TA_RangeType_TA_RangeType_RealBody,
+            RangeType::HighLow => crate::TA_RangeType_TA_RangeType_HighLow,
+            RangeType::Shadows => crate::TA_RangeType_TA_RangeType_Shadows,
+        }
+    }
+}
+
+///
+/// One candle setting: a candle part is compared to `factor` times the average `range_type` of
+/// the `avg_period` previous candles (or of the candle itself when `avg_period` is 0).
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleSetting {
+    pub range_type: RangeType,
+    pub avg_period: u32,
+    pub factor: f64,
+}
+
+impl CandleSetting {
+    const fn new(range_type: RangeType, avg_period: u32, factor: f64) -> CandleSetting {
+        CandleSetting {
+            range_type,
+            avg_period,
+            factor,
+        }
+    }
+}
+
+///
+/// Every setting used by the candlestick pattern functions (`wrapper::cdl*`).
+///
+/// The settings are process-wide, like the unstable periods: [`CandleSettings::apply`] changes
+/// them for every later computation and they are kept across `TA_Shutdown`/`TA_Initialize`, see
+/// [`crate::TaLib`]. `Default` gives the TA-Lib defaults, tuned for equities.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::CandleSettings;
+///
+/// let mut settings = CandleSettings::default();
+/// // a doji body is at most 5% of the candle instead of 10%
+/// settings.body_doji.factor = 0.05;
+/// settings.apply().unwrap();
+/// assert_eq!(CandleSettings::current().body_doji.factor, 0.05);
+///
+/// CandleSettings::restore().unwrap();
+/// assert_eq!(CandleSettings::current(), CandleSettings::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleSettings {
+    pub body_long: CandleSetting,
+    pub body_very_long: CandleSetting,
+    pub body_short: CandleSetting,
+    pub body_doji: CandleSetting,
+    pub shadow_long: CandleSetting,
+    pub shadow_very_long: CandleSetting,
+    pub shadow_short: CandleSetting,
+    pub shadow_very_short: CandleSetting,
+    pub near: CandleSetting,
+    pub far: CandleSetting,
+    pub equal: CandleSetting,
+}
+
+impl CandleSettings {
+    /// The TA-Lib defaults, `TA_CandleDefaultSettings` in the C library.
+    pub const DEFAULT: CandleSettings = CandleSettings {
+        body_long: CandleSetting::new(RangeType::RealBody, 10, 1.0),
+        body_very_long: CandleSetting::new(RangeType::RealBody, 10, 3.0),
+        body_short: CandleSetting::new(RangeType::RealBody, 10, 1.0),
+        body_doji: CandleSetting::new(RangeType::HighLow, 10, 0.1),
+        shadow_long: CandleSetting::new(RangeType::RealBody, 0, 1.0),
+        shadow_very_long: CandleSetting::new(RangeType::RealBody, 0, 2.0),
+        shadow_short: CandleSetting::new(RangeType::Shadows, 10, 1.0),
+        shadow_very_short: CandleSetting::new(RangeType::HighLow, 10, 0.1),
+        near: CandleSetting::new(RangeType::HighLow, 5, 0.2),
+        far: CandleSetting::new(RangeType::HighLow, 5, 0.6),
+        equal: CandleSetting::new(RangeType::HighLow, 5, 0.05),
+    };
+
+    /// The settings currently used by the candlestick pattern functions.
+    pub fn current() -> CandleSettings {
+        talib::settings().candle.unwrap_or(CandleSettings::DEFAULT)
+    }
+
+    /// Uses these settings for every later computation, `TA_SetCandleSettings` for each of them.
+    ///
+    /// Fails with [`TaError::ParamOutOfRange`] if an average period is above 100000 or a factor
+    /// is negative or not finite. Like [`crate::UnstablePeriod::set`], it waits until no other
+    /// thread holds a scoped override.
+    pub fn apply(&self) -> Result<(), TaError> {
+        self.validate()?;
+        let _scope = talib::lock_scope();
+        talib::configure(|settings| settings.candle = Some(*self))
+    }
+
+    /// Goes back to the TA-Lib defaults, `TA_RestoreCandleDefaultSettings` for every setting.
+    ///
+    /// The defaults come from the linked C library itself, [`CandleSettings::current`] then
+    /// reports [`CandleSettings::DEFAULT`].
+    pub fn restore() -> Result<(), TaError> {
+        let _scope = talib::lock_scope();
+        talib::configure(|settings| settings.candle = None)
+    }
+
+    /// The setting of `setting_type`.
+    pub fn get(&self, setting_type: CandleSettingType) -> &CandleSetting {
+        match setting_type {
+            CandleSettingType::BodyLong => &self.body_long,
+            CandleSettingType::BodyVeryLong => &self.body_very_long,
+            CandleSettingType::BodyShort => &self.body_short,
+            CandleSettingType::BodyDoji => &self.body_doji,
+            CandleSettingType::ShadowLong => &self.shadow_long,
+            CandleSettingType::ShadowVeryLong => &self.shadow_very_long,
+            CandleSettingType::ShadowShort => &self.shadow_short,
+            CandleSettingType::ShadowVeryShort => &self.shadow_very_short,
+            CandleSettingType::Near => &self.near,
+            CandleSettingType::Far => &self.far,
+            CandleSettingType::Equal => &self.equal,
+        }
+    }
+
+    /// The setting of `setting_type`, to change it in place.
+    pub fn get_mut(&mut self, setting_type: CandleSettingType) -> &mut CandleSetting {
+        match setting_type {
+            CandleSettingType::BodyLong => &mut self.body_long,
+            CandleSettingType::BodyVeryLong => &mut self.body_very_long,
+            CandleSettingType::BodyShort => &mut self.body_short,
+            CandleSettingType::BodyDoji => &mut self.body_doji,
+            CandleSettingType::ShadowLong => &mut self.shadow_long,
+            CandleSettingType::ShadowVeryLong => &mut self.shadow_very_long,
+            CandleSettingType::ShadowShort => &mut self.shadow_short,
+            CandleSettingType::ShadowVeryShort => &mut self.shadow_very_short,
+            CandleSettingType::Near => &mut self.near,
+            CandleSettingType::Far => &mut self.far,
+            CandleSettingType::Equal => &mut self.equal,
+        }
+    }
+
+    fn validate(&self) -> Result<(), TaError> {
+        for setting_type in CandleSettingType::ALL {
+            let setting = self.get(setting_type);
+            if setting.avg_period > 100000 {
+                return Err(TaError::ParamOutOfRange {
+                    name: "avg_period",
+                    range: "from 0 to 100000",
+                });
+            }
+            if !(0.0..=3e37).contains(&setting.factor) {
+                return Err(TaError::ParamOutOfRange {
+                    name: "factor",
+                    range: "from 0.0 to 3e37",
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for CandleSettings {
+    fn default() -> Self {
+        CandleSettings::DEFAULT
+    }
+}
+
+///
+/// Candle settings by name, so that a configuration can pick them with a string.
+///
+/// `"default"` is always there and gives the TA-Lib defaults, unless it is replaced. With the
+/// `serde` feature this is a plain map from names to [`CandleSettings`], so presets can be kept
+/// in a configuration file.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::{CandlePresets, CandleSettings};
+///
+/// let mut presets = CandlePresets::new();
+/// let mut fx = CandleSettings::default();
+/// fx.body_doji.factor = 0.03;
+/// fx.equal.factor = 0.02;
+/// presets.insert("fx", fx);
+///
+/// presets.apply("fx").unwrap();
+/// assert_eq!(CandleSettings::current(), fx);
+/// presets.apply("default").unwrap();
+/// assert_eq!(CandleSettings::current(), CandleSettings::default());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct CandlePresets {
+    presets: BTreeMap<String, CandleSettings>,
+}
+
+impl CandlePresets {
+    /// Presets holding only `"default"`.
+    pub fn new() -> CandlePresets {
+        CandlePresets::default()
+    }
+
+    /// Adds a preset, returning the one it replaces.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        settings: CandleSettings,
+    ) -> Option<CandleSettings> {
+        self.presets.insert(name.into(), settings)
+    }
+
+    /// The preset called `name`.
+    pub fn get(&self, name: &str) -> Option<CandleSettings> {
+        match self.presets.get(name) {
+            Some(settings) => Some(*settings),
+            None if name == "default" => Some(CandleSettings::DEFAULT),
+            None => None,
+        }
+    }
+
+    /// Names of the presets, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        let default = (!self.presets.contains_key("default")).then_some("default");
+        let mut names: Vec<&str> = self.presets.keys().map(String::as_str).collect();
+        names.extend(default);
+        names.sort_unstable();
+        names.into_iter()
+    }
+
+    /// Applies the preset called `name`, failing with [`TaError::BadParam`] if there is none.
+    pub fn apply(&self, name: &str) -> Result<(), TaError> {
+        self.get(name).ok_or(TaError::BadParam)?.apply()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::talib;
+use crate::TaError;
+
+///
+/// Compatibility mode of the C library, `TA_SetCompatibility` of TA-Lib.
+///
+/// In `Metastock` mode the EMA-based functions (`ema`, `dema`, `tema`, `t3`, `trix`, `macd`,
+/// `apo`, `ppo`, `ma` with an EMA-based [`crate::MaType`]...) seed their average with the first
+/// input value instead of a simple average, and `rsi`/`cmo` start one bar earlier, matching the
+/// values exported by Metastock.
+///
+/// The mode is process-wide and kept across `TA_Shutdown`/`TA_Initialize`, see [`crate::TaLib`].
+/// The `_compat` wrappers (such as [`crate::wrapper::ema_compat`]) use another mode for a single
+/// call without changing it. Every function whose output depends on the mode has one: the
+/// EMA-based functions, `rsi`, `cmo`, and every function taking a [`crate::MaType`] (`bbands`,
+/// `stoch`, `stochf`, `stochrsi`, `mavp`...), which depends on it through the EMA-based types.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::Compatibility;
+///
+/// assert_eq!(Compatibility::get(), Compatibility::Default);
+/// let close_prices: Vec<f64> = (0..50).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let (ema, begin) =
+///     rust_ta_lib::wrapper::ema_compat(&close_prices, 10, Compatibility::Metastock).unwrap();
+/// println!("{} values starting at {}", ema.len(), begin);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compatibility {
+    /// Behaviour of TA-Lib itself
+    #[default]
+    Default,
+    /// Seeds and lookbacks of Metastock
+    Metastock,
+}
+
+impl Compatibility {
+    /// The compatibility mode currently used.
+    pub fn get() -> Compatibility {
+        talib::settings().compatibility
+    }
+
+    /// Sets the compatibility mode of every later computation.
+    ///
+    /// Like [`crate::UnstablePeriod::set`], it waits until no other thread holds a scoped override.
+    pub fn set(compatibility: Compatibility) -> Result<(), TaError> {
+        let _scope = talib::lock_scope();
+        talib::configure(|settings| settings.compatibility = compatibility)
+    }
+}
+
+impl From<Compatibility> for crate::TA_Compatibility {
+    fn from(compatibility: Compatibility) -> Self {
+        match compatibility {
+            Compatibility::Default => crate::TA_Compatibility_TA_COMPATIBILITY_DEFAULT,
+            Compatibility::Metastock => crate::TA_Compatibility_TA_COMPATIBILITY_METASTOCK,
+        }
+    }
+}
+
+/// Fails with [`TaError::BadParam`] for a value that is not a `TA_COMPATIBILITY_*` constant.
+impl TryFrom<crate::TA_Compatibility> for Compatibility {
+    type Error = TaError;
+
+    fn try_from(value: crate::TA_Compatibility) -> Result<Self, Self::Error> {
+        match value {
+            crate::TA_Compatibility_TA_COMPATIBILITY_DEFAULT => Ok(Compatibility::Default),
+            crate::TA_Compatibility_TA_COMPATIBILITY_METASTOCK => Ok(Compatibility::Metastock),
+            _ => Err(TaError::BadParam),
+        }
+    }
+}
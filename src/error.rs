@@ -0,0 +1,161 @@
+use std::ffi::CStr;
+use std::fmt;
+
+///
+/// Error returned by the wrappers instead of panicking.
+///
+/// Every `TA_RetCode_*` constant of the C library other than `TA_SUCCESS` maps to one variant.
+/// The human readable message comes from the C library itself (`TA_SetRetCodeInfo`).
+/// The remaining variants are checks done on the Rust side before calling the C library.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaError {
+    /// TA_LIB_NOT_INITIALIZE
+    LibNotInitialize,
+    /// TA_BAD_PARAM
+    BadParam,
+    /// TA_ALLOC_ERR
+    AllocErr,
+    /// TA_GROUP_NOT_FOUND
+    GroupNotFound,
+    /// TA_FUNC_NOT_FOUND
+    FuncNotFound,
+    /// TA_INVALID_HANDLE
+    InvalidHandle,
+    /// TA_INVALID_PARAM_HOLDER
+    InvalidParamHolder,
+    /// TA_INVALID_PARAM_HOLDER_TYPE
+    InvalidParamHolderType,
+    /// TA_INVALID_PARAM_FUNCTION
+    InvalidParamFunction,
+    /// TA_INPUT_NOT_ALL_INITIALIZE
+    InputNotAllInitialize,
+    /// TA_OUTPUT_NOT_ALL_INITIALIZE
+    OutputNotAllInitialize,
+    /// TA_OUT_OF_RANGE_START_INDEX
+    OutOfRangeStartIndex,
+    /// TA_OUT_OF_RANGE_END_INDEX
+    OutOfRangeEndIndex,
+    /// TA_INVALID_LIST_TYPE
+    InvalidListType,
+    /// TA_BAD_OBJECT
+    BadObject,
+    /// TA_NOT_SUPPORTED
+    NotSupported,
+    /// TA_INTERNAL_ERROR, holding the full return code (`TA_INTERNAL_ERROR + id`)
+    InternalError(crate::TA_RetCode),
+    /// TA_UNKNOWN_ERR, or any return code this crate does not know about
+    UnknownErr(crate::TA_RetCode),
+    /// The input series of a function taking several of them do not have the same length
+    InputLengthMismatch { expected: usize, found: usize },
+    /// A buffer given to an `_into` function cannot hold every value of the output
+    OutputTooSmall { required: usize, found: usize },
+    /// A parameter is outside of the range TA-Lib accepts for it, `range` describes that range
+    ParamOutOfRange {
+        name: &'static str,
+        range: &'static str,
+    },
+}
+
+impl TaError {
+    /// Maps a non-success `TA_RetCode` returned by the C library to its variant.
+    pub(crate) fn from_ret_code(ret_code: crate::TA_RetCode) -> TaError {
+        match ret_code {
+            crate::TA_RetCode_TA_LIB_NOT_INITIALIZE => TaError::LibNotInitialize,
+            crate::TA_RetCode_TA_BAD_PARAM => TaError::BadParam,
+            crate::TA_RetCode_TA_ALLOC_ERR => TaError::AllocErr,
+            crate::TA_RetCode_TA_GROUP_NOT_FOUND => TaError::GroupNotFound,
+            crate::TA_RetCode_TA_FUNC_NOT_FOUND => TaError::FuncNotFound,
+            crate::TA_RetCode_TA_INVALID_HANDLE => TaError::InvalidHandle,
+            crate::TA_RetCode_TA_INVALID_PARAM_HOLDER => TaError::InvalidParamHolder,
+            crate::TA_RetCode_TA_INVALID_PARAM_HOLDER_TYPE => TaError::InvalidParamHolderType,
+            crate::TA_RetCode_TA_INVALID_PARAM_FUNCTION => TaError::InvalidParamFunction,
+            crate::TA_RetCode_TA_INPUT_NOT_ALL_INITIALIZE => TaError::InputNotAllInitialize,
+            crate::TA_RetCode_TA_OUTPUT_NOT_ALL_INITIALIZE => TaError::OutputNotAllInitialize,
+            crate::TA_RetCode_TA_OUT_OF_RANGE_START_INDEX => TaError::OutOfRangeStartIndex,
+            crate::TA_RetCode_TA_OUT_OF_RANGE_END_INDEX => TaError::OutOfRangeEndIndex,
+            crate::TA_RetCode_TA_INVALID_LIST_TYPE => TaError::InvalidListType,
+            crate::TA_RetCode_TA_BAD_OBJECT => TaError::BadObject,
+            crate::TA_RetCode_TA_NOT_SUPPORTED => TaError::NotSupported,
+            code if (crate::TA_RetCode_TA_INTERNAL_ERROR
+                ..crate::TA_RetCode_TA_INTERNAL_ERROR + 1000)
+                .contains(&code) =>
+            {
+                TaError::InternalError(code)
+            }
+            code => TaError::UnknownErr(code),
+        }
+    }
+
+    /// The `TA_RetCode` value reported by the C library for this error.
+    pub fn ret_code(&self) -> crate::TA_RetCode {
+        match *self {
+            TaError::LibNotInitialize => crate::TA_RetCode_TA_LIB_NOT_INITIALIZE,
+            TaError::BadParam => crate::TA_RetCode_TA_BAD_PARAM,
+            TaError::AllocErr => crate::TA_RetCode_TA_ALLOC_ERR,
+            TaError::GroupNotFound => crate::TA_RetCode_TA_GROUP_NOT_FOUND,
+            TaError::FuncNotFound => crate::TA_RetCode_TA_FUNC_NOT_FOUND,
+            TaError::InvalidHandle => crate::TA_RetCode_TA_INVALID_HANDLE,
+            TaError::InvalidParamHolder => crate::TA_RetCode_TA_INVALID_PARAM_HOLDER,
+            TaError::InvalidParamHolderType => crate::TA_RetCode_TA_INVALID_PARAM_HOLDER_TYPE,
+            TaError::InvalidParamFunction => crate::TA_RetCode_TA_INVALID_PARAM_FUNCTION,
+            TaError::InputNotAllInitialize => crate::TA_RetCode_TA_INPUT_NOT_ALL_INITIALIZE,
+            TaError::OutputNotAllInitialize => crate::TA_RetCode_TA_OUTPUT_NOT_ALL_INITIALIZE,
+            TaError::OutOfRangeStartIndex => crate::TA_RetCode_TA_OUT_OF_RANGE_START_INDEX,
+            TaError::OutOfRangeEndIndex => crate::TA_RetCode_TA_OUT_OF_RANGE_END_INDEX,
+            TaError::InvalidListType => crate::TA_RetCode_TA_INVALID_LIST_TYPE,
+            TaError::BadObject => crate::TA_RetCode_TA_BAD_OBJECT,
+            TaError::NotSupported => crate::TA_RetCode_TA_NOT_SUPPORTED,
+            TaError::InternalError(code) => code,
+            TaError::UnknownErr(code) => code,
+            // the C library would have read past the end of the shorter series
+            TaError::InputLengthMismatch { .. } => crate::TA_RetCode_TA_BAD_PARAM,
+            TaError::OutputTooSmall { .. } => crate::TA_RetCode_TA_BAD_PARAM,
+            TaError::ParamOutOfRange { .. } => crate::TA_RetCode_TA_BAD_PARAM,
+        }
+    }
+}
+
+impl fmt::Display for TaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TaError::InputLengthMismatch { expected, found } => {
+                return write!(
+                    f,
+                    "input series have different lengths ({} and {})",
+                    expected, found
+                );
+            }
+            TaError::OutputTooSmall { required, found } => {
+                return write!(
+                    f,
+                    "output buffer too small ({} values needed, {} given)",
+                    required, found
+                );
+            }
+            TaError::ParamOutOfRange { name, range } => {
+                return write!(f, "{} is out of range, expected a value {}", name, range);
+            }
+            _ => {}
+        }
+        let mut info = crate::TA_RetCodeInfo {
+            enumStr: std::ptr::null(),
+            infoStr: std::ptr::null(),
+        };
+        // TA_SetRetCodeInfo only reads static tables, it does not need TA_Initialize
+        unsafe { crate::TA_SetRetCodeInfo(self.ret_code(), &mut info) };
+        if info.enumStr.is_null() || info.infoStr.is_null() {
+            return write!(f, "TA-Lib error code {}", self.ret_code());
+        }
+        let (name, message) = unsafe {
+            (
+                CStr::from_ptr(info.enumStr).to_string_lossy(),
+                CStr::from_ptr(info.infoStr).to_string_lossy(),
+            )
+        };
+        write!(f, "{} ({})", message, name)
+    }
+}
+
+impl std::error::Error for TaError {}
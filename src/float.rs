@@ -0,0 +1,2023 @@
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f64 {}
+    impl Sealed for f32 {}
+}
+
+///
+/// Input type of the wrappers: `f64` inputs go through the `TA_*` functions of TA-Lib and `f32`
+/// inputs through the `TA_S_*` ones. Outputs are `f64` either way.
+///
+/// This trait is sealed, it is only implemented for `f64` and `f32`.
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f32> = vec![1.08701, 1.08712, 1.08708, 1.08717, 1.08711, 1.08701];
+/// // calls TA_S_MA, the same call on a Vec<f64> goes through TA_MA
+/// let (sma_values, begin) = rust_ta_lib::wrapper::sma(3, &close_prices).unwrap();
+/// assert_eq!(sma_values.len() + begin as usize, close_prices.len());
+/// ```
+pub trait TaFloat: private::Sealed + Copy + 'static {
+    #[doc(hidden)]
+    const FFI: &'static Ffi<Self>;
+}
+
+impl TaFloat for f64 {
+    const FFI: &'static Ffi<f64> = &FFI_F64;
+}
+
+impl TaFloat for f32 {
+    const FFI: &'static Ffi<f32> = &FFI_F32;
+}
+
+/// The TA-Lib functions taking inputs of type `T`, one field per function
+#[doc(hidden)]
+pub struct Ffi<T> {
+    pub(crate) accbands: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) bbands: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        f64,
+        f64,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) dema: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ema: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_trendline: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) kama: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ma: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) mama: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        f64,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) mavp: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) midpoint: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) midprice: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        f64,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sarext: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        f64,
+        f64,
+        f64,
+        f64,
+        f64,
+        f64,
+        f64,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) t3: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) tema: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) trima: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) wma: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) adx: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) adxr: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) apo: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) aroon: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) aroonosc: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) bop: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) cci: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) cmo: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) dx: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) imi: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) macd: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) macdext: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) macdfix: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) mfi: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) minus_di: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) minus_dm: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) mom: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) plus_di: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) plus_dm: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ppo: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) roc: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) rocp: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) rocr: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) rocr100: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) rsi: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) stoch: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) stochf: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) stochrsi: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_MAType,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) trix: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ultosc: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) willr: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ad: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) adosc: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) obv: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) atr: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) natr: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) trange: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) avgprice: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) medprice: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) typprice: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) wclprice: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_dcperiod: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_dcphase: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_phasor: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_sine: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ht_trendmode: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) avgdev: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) beta: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) correl: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) linearreg: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) linearreg_angle: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) linearreg_intercept: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) linearreg_slope: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) stddev: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) tsf: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) var: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) acos: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) asin: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) atan: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ceil: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) cos: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) cosh: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) exp: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) floor: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) ln: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) log10: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sin: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sinh: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sqrt: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) tan: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) tanh: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) add: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) sub: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) mult: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) div: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) max: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) maxindex: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) min: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) minindex: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) minmax: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) minmaxindex: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) sum: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut f64,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl2crows: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3blackcrows: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3inside: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3linestrike: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3outside: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3starsinsouth: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdl3whitesoldiers: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlabandonedbaby: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdladvanceblock: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlbelthold: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlbreakaway: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlclosingmarubozu: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlconcealbabyswall: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlcounterattack: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdldarkcloudcover: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdldoji: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdldojistar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdldragonflydoji: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlengulfing: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdleveningdojistar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdleveningstar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlgapsidesidewhite: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlgravestonedoji: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhammer: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhangingman: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlharami: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlharamicross: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhighwave: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhikkake: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhikkakemod: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlhomingpigeon: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlidentical3crows: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlinneck: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlinvertedhammer: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlkicking: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlkickingbylength: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlladderbottom: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdllongleggeddoji: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdllongline: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlmarubozu: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlmatchinglow: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlmathold: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlmorningdojistar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlmorningstar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        f64,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlonneck: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlpiercing: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlrickshawman: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlrisefall3methods: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlseparatinglines: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlshootingstar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlshortline: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlspinningtop: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlstalledpattern: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlsticksandwich: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdltakuri: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdltasukigap: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlthrusting: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdltristar: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlunique3river: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlupsidegap2crows: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+    pub(crate) cdlxsidegap3methods: unsafe extern "C" fn(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        *const T,
+        *const T,
+        *const T,
+        *const T,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+}
+
+static FFI_F64: Ffi<f64> = Ffi {
+    accbands: crate::TA_ACCBANDS,
+    bbands: crate::TA_BBANDS,
+    dema: crate::TA_DEMA,
+    ema: crate::TA_EMA,
+    ht_trendline: crate::TA_HT_TRENDLINE,
+    kama: crate::TA_KAMA,
+    ma: crate::TA_MA,
+    mama: crate::TA_MAMA,
+    mavp: crate::TA_MAVP,
+    midpoint: crate::TA_MIDPOINT,
+    midprice: crate::TA_MIDPRICE,
+    sar: crate::TA_SAR,
+    sarext: crate::TA_SAREXT,
+    t3: crate::TA_T3,
+    tema: crate::TA_TEMA,
+    trima: crate::TA_TRIMA,
+    wma: crate::TA_WMA,
+    adx: crate::TA_ADX,
+    adxr: crate::TA_ADXR,
+    apo: crate::TA_APO,
+    aroon: crate::TA_AROON,
+    aroonosc: crate::TA_AROONOSC,
+    bop: crate::TA_BOP,
+    cci: crate::TA_CCI,
+    cmo: crate::TA_CMO,
+    dx: crate::TA_DX,
+    imi: crate::TA_IMI,
+    macd: crate::TA_MACD,
+    macdext: crate::TA_MACDEXT,
+    macdfix: crate::TA_MACDFIX,
+    mfi: crate::TA_MFI,
+    minus_di: crate::TA_MINUS_DI,
+    minus_dm: crate::TA_MINUS_DM,
+    mom: crate::TA_MOM,
+    plus_di: crate::TA_PLUS_DI,
+    plus_dm: crate::TA_PLUS_DM,
+    ppo: crate::TA_PPO,
+    roc: crate::TA_ROC,
+    rocp: crate::TA_ROCP,
+    rocr: crate::TA_ROCR,
+    rocr100: crate::TA_ROCR100,
+    rsi: crate::TA_RSI,
+    stoch: crate::TA_STOCH,
+    stochf: crate::TA_STOCHF,
+    stochrsi: crate::TA_STOCHRSI,
+    trix: crate::TA_TRIX,
+    ultosc: crate::TA_ULTOSC,
+    willr: crate::TA_WILLR,
+    ad: crate::TA_AD,
+    adosc: crate::TA_ADOSC,
+    obv: crate::TA_OBV,
+    atr: crate::TA_ATR,
+    natr: crate::TA_NATR,
+    trange: crate::TA_TRANGE,
+    avgprice: crate::TA_AVGPRICE,
+    medprice: crate::TA_MEDPRICE,
+    typprice: crate::TA_TYPPRICE,
+    wclprice: crate::TA_WCLPRICE,
+    ht_dcperiod: crate::TA_HT_DCPERIOD,
+    ht_dcphase: crate::TA_HT_DCPHASE,
+    ht_phasor: crate::TA_HT_PHASOR,
+    ht_sine: crate::TA_HT_SINE,
+    ht_trendmode: crate::TA_HT_TRENDMODE,
+    avgdev: crate::TA_AVGDEV,
+    beta: crate::TA_BETA,
+    correl: crate::TA_CORREL,
+    linearreg: crate::TA_LINEARREG,
+    linearreg_angle: crate::TA_LINEARREG_ANGLE,
+    linearreg_intercept: crate::TA_LINEARREG_INTERCEPT,
+    linearreg_slope: crate::TA_LINEARREG_SLOPE,
+    stddev: crate::TA_STDDEV,
+    tsf: crate::TA_TSF,
+    var: crate::TA_VAR,
+    acos: crate::TA_ACOS,
+    asin: crate::TA_ASIN,
+    atan: crate::TA_ATAN,
+    ceil: crate::TA_CEIL,
+    cos: crate::TA_COS,
+    cosh: crate::TA_COSH,
+    exp: crate::TA_EXP,
+    floor: crate::TA_FLOOR,
+    ln: crate::TA_LN,
+    log10: crate::TA_LOG10,
+    sin: crate::TA_SIN,
+    sinh: crate::TA_SINH,
+    sqrt: crate::TA_SQRT,
+    tan: crate::TA_TAN,
+    tanh: crate::TA_TANH,
+    add: crate::TA_ADD,
+    sub: crate::TA_SUB,
+    mult: crate::TA_MULT,
+    div: crate::TA_DIV,
+    max: crate::TA_MAX,
+    maxindex: crate::TA_MAXINDEX,
+    min: crate::TA_MIN,
+    minindex: crate::TA_MININDEX,
+    minmax: crate::TA_MINMAX,
+    minmaxindex: crate::TA_MINMAXINDEX,
+    sum: crate::TA_SUM,
+    cdl2crows: crate::TA_CDL2CROWS,
+    cdl3blackcrows: crate::TA_CDL3BLACKCROWS,
+    cdl3inside: crate::TA_CDL3INSIDE,
+    cdl3linestrike: crate::TA_CDL3LINESTRIKE,
+    cdl3outside: crate::TA_CDL3OUTSIDE,
+    cdl3starsinsouth: crate::TA_CDL3STARSINSOUTH,
+    cdl3whitesoldiers: crate::TA_CDL3WHITESOLDIERS,
+    cdlabandonedbaby: crate::TA_CDLABANDONEDBABY,
+    cdladvanceblock: crate::TA_CDLADVANCEBLOCK,
+    cdlbelthold: crate::TA_CDLBELTHOLD,
+    cdlbreakaway: crate::TA_CDLBREAKAWAY,
+    cdlclosingmarubozu: crate::TA_CDLCLOSINGMARUBOZU,
+    cdlconcealbabyswall: crate::TA_CDLCONCEALBABYSWALL,
+    cdlcounterattack: crate::TA_CDLCOUNTERATTACK,
+    cdldarkcloudcover: crate::TA_CDLDARKCLOUDCOVER,
+    cdldoji: crate::TA_CDLDOJI,
+    cdldojistar: crate::TA_CDLDOJISTAR,
+    cdldragonflydoji: crate::TA_CDLDRAGONFLYDOJI,
+    cdlengulfing: crate::TA_CDLENGULFING,
+    cdleveningdojistar: crate::TA_CDLEVENINGDOJISTAR,
+    cdleveningstar: crate::TA_CDLEVENINGSTAR,
+    cdlgapsidesidewhite: crate::TA_CDLGAPSIDESIDEWHITE,
+    cdlgravestonedoji: crate::TA_CDLGRAVESTONEDOJI,
+    cdlhammer: crate::TA_CDLHAMMER,
+    cdlhangingman: crate::TA_CDLHANGINGMAN,
+    cdlharami: crate::TA_CDLHARAMI,
+    cdlharamicross: crate::TA_CDLHARAMICROSS,
+    cdlhighwave: crate::TA_CDLHIGHWAVE,
+    cdlhikkake: crate::TA_CDLHIKKAKE,
+    cdlhikkakemod: crate::TA_CDLHIKKAKEMOD,
+    cdlhomingpigeon: crate::TA_CDLHOMINGPIGEON,
+    cdlidentical3crows: crate::TA_CDLIDENTICAL3CROWS,
+    cdlinneck: crate::TA_CDLINNECK,
+    cdlinvertedhammer: crate::TA_CDLINVERTEDHAMMER,
+    cdlkicking: crate::TA_CDLKICKING,
+    cdlkickingbylength: crate::TA_CDLKICKINGBYLENGTH,
+    cdlladderbottom: crate::TA_CDLLADDERBOTTOM,
+    cdllongleggeddoji: crate::TA_CDLLONGLEGGEDDOJI,
+    cdllongline: crate::TA_CDLLONGLINE,
+    cdlmarubozu: crate::TA_CDLMARUBOZU,
+    cdlmatchinglow: crate::TA_CDLMATCHINGLOW,
+    cdlmathold: crate::TA_CDLMATHOLD,
+    cdlmorningdojistar: crate::TA_CDLMORNINGDOJISTAR,
+    cdlmorningstar: crate::TA_CDLMORNINGSTAR,
+    cdlonneck: crate::TA_CDLONNECK,
+    cdlpiercing: crate::TA_CDLPIERCING,
+    cdlrickshawman: crate::TA_CDLRICKSHAWMAN,
+    cdlrisefall3methods: crate::TA_CDLRISEFALL3METHODS,
+    cdlseparatinglines: crate::TA_CDLSEPARATINGLINES,
+    cdlshootingstar: crate::TA_CDLSHOOTINGSTAR,
+    cdlshortline: crate::TA_CDLSHORTLINE,
+    cdlspinningtop: crate::TA_CDLSPINNINGTOP,
+    cdlstalledpattern: crate::TA_CDLSTALLEDPATTERN,
+    cdlsticksandwich: crate::TA_CDLSTICKSANDWICH,
+    cdltakuri: crate::TA_CDLTAKURI,
+    cdltasukigap: crate::TA_CDLTASUKIGAP,
+    cdlthrusting: crate::TA_CDLTHRUSTING,
+    cdltristar: crate::TA_CDLTRISTAR,
+    cdlunique3river: crate::TA_CDLUNIQUE3RIVER,
+    cdlupsidegap2crows: crate::TA_CDLUPSIDEGAP2CROWS,
+    cdlxsidegap3methods: crate::TA_CDLXSIDEGAP3METHODS,
+};
+
+static FFI_F32: Ffi<f32> = Ffi {
+    accbands: crate::TA_S_ACCBANDS,
+    bbands: crate::TA_S_BBANDS,
+    dema: crate::TA_S_DEMA,
+    ema: crate::TA_S_EMA,
+    ht_trendline: crate::TA_S_HT_TRENDLINE,
+    kama: crate::TA_S_KAMA,
+    ma: crate::TA_S_MA,
+    mama: crate::TA_S_MAMA,
+    mavp: crate::TA_S_MAVP,
+    midpoint: crate::TA_S_MIDPOINT,
+    midprice: crate::TA_S_MIDPRICE,
+    sar: crate::TA_S_SAR,
+    sarext: crate::TA_S_SAREXT,
+    t3: crate::TA_S_T3,
+    tema: crate::TA_S_TEMA,
+    trima: crate::TA_S_TRIMA,
+    wma: crate::TA_S_WMA,
+    adx: crate::TA_S_ADX,
+    adxr: crate::TA_S_ADXR,
+    apo: crate::TA_S_APO,
+    aroon: crate::TA_S_AROON,
+    aroonosc: crate::TA_S_AROONOSC,
+    bop: crate::TA_S_BOP,
+    cci: crate::TA_S_CCI,
+    cmo: crate::TA_S_CMO,
+    dx: crate::TA_S_DX,
+    imi: crate::TA_S_IMI,
+    macd: crate::TA_S_MACD,
+    macdext: crate::TA_S_MACDEXT,
+    macdfix: crate::TA_S_MACDFIX,
+    mfi: crate::TA_S_MFI,
+    minus_di: crate::TA_S_MINUS_DI,
+    minus_dm: crate::TA_S_MINUS_DM,
+    mom: crate::TA_S_MOM,
+    plus_di: crate::TA_S_PLUS_DI,
+    plus_dm: crate::TA_S_PLUS_DM,
+    ppo: crate::TA_S_PPO,
+    roc: crate::TA_S_ROC,
+    rocp: crate::TA_S_ROCP,
+    rocr: crate::TA_S_ROCR,
+    rocr100: crate::TA_S_ROCR100,
+    rsi: crate::TA_S_RSI,
+    stoch: crate::TA_S_STOCH,
+    stochf: crate::TA_S_STOCHF,
+    stochrsi: crate::TA_S_STOCHRSI,
+    trix: crate::TA_S_TRIX,
+    ultosc: crate::TA_S_ULTOSC,
+    willr: crate::TA_S_WILLR,
+    ad: crate::TA_S_AD,
+    adosc: crate::TA_S_ADOSC,
+    obv: crate::TA_S_OBV,
+    atr: crate::TA_S_ATR,
+    natr: crate::TA_S_NATR,
+    trange: crate::TA_S_TRANGE,
+    avgprice: crate::TA_S_AVGPRICE,
+    medprice: crate::TA_S_MEDPRICE,
+    typprice: crate::TA_S_TYPPRICE,
+    wclprice: crate::TA_S_WCLPRICE,
+    ht_dcperiod: crate::TA_S_HT_DCPERIOD,
+    ht_dcphase: crate::TA_S_HT_DCPHASE,
+    ht_phasor: crate::TA_S_HT_PHASOR,
+    ht_sine: crate::TA_S_HT_SINE,
+    ht_trendmode: crate::TA_S_HT_TRENDMODE,
+    avgdev: crate::TA_S_AVGDEV,
+    beta: crate::TA_S_BETA,
+    correl: crate::TA_S_CORREL,
+    linearreg: crate::TA_S_LINEARREG,
+    linearreg_angle: crate::TA_S_LINEARREG_ANGLE,
+    linearreg_intercept: crate::TA_S_LINEARREG_INTERCEPT,
+    linearreg_slope: crate::TA_S_LINEARREG_SLOPE,
+    stddev: crate::TA_S_STDDEV,
+    tsf: crate::TA_S_TSF,
+    var: crate::TA_S_VAR,
+    acos: crate::TA_S_ACOS,
+    asin: crate::TA_S_ASIN,
+    atan: crate::TA_S_ATAN,
+    ceil: crate::TA_S_CEIL,
+    cos: crate::TA_S_COS,
+    cosh: crate::TA_S_COSH,
+    exp: crate::TA_S_EXP,
+    floor: crate::TA_S_FLOOR,
+    ln: crate::TA_S_LN,
+    log10: crate::TA_S_LOG10,
+    sin: crate::TA_S_SIN,
+    sinh: crate::TA_S_SINH,
+    sqrt: crate::TA_S_SQRT,
+    tan: crate::TA_S_TAN,
+    tanh: crate::TA_S_TANH,
+    add: crate::TA_S_ADD,
+    sub: crate::TA_S_SUB,
+    mult: crate::TA_S_MULT,
+    div: crate::TA_S_DIV,
+    max: crate::TA_S_MAX,
+    maxindex: crate::TA_S_MAXINDEX,
+    min: crate::TA_S_MIN,
+    minindex: crate::TA_S_MININDEX,
+    minmax: crate::TA_S_MINMAX,
+    minmaxindex: crate::TA_S_MINMAXINDEX,
+    sum: crate::TA_S_SUM,
+    cdl2crows: crate::TA_S_CDL2CROWS,
+    cdl3blackcrows: crate::TA_S_CDL3BLACKCROWS,
+    cdl3inside: crate::TA_S_CDL3INSIDE,
+    cdl3linestrike: crate::TA_S_CDL3LINESTRIKE,
+    cdl3outside: crate::TA_S_CDL3OUTSIDE,
+    cdl3starsinsouth: crate::TA_S_CDL3STARSINSOUTH,
+    cdl3whitesoldiers: crate::TA_S_CDL3WHITESOLDIERS,
+    cdlabandonedbaby: crate::TA_S_CDLABANDONEDBABY,
+    cdladvanceblock: crate::TA_S_CDLADVANCEBLOCK,
+    cdlbelthold: crate::TA_S_CDLBELTHOLD,
+    cdlbreakaway: crate::TA_S_CDLBREAKAWAY,
+    cdlclosingmarubozu: crate::TA_S_CDLCLOSINGMARUBOZU,
+    cdlconcealbabyswall: crate::TA_S_CDLCONCEALBABYSWALL,
+    cdlcounterattack: crate::TA_S_CDLCOUNTERATTACK,
+    cdldarkcloudcover: crate::TA_S_CDLDARKCLOUDCOVER,
+    cdldoji: crate::TA_S_CDLDOJI,
+    cdldojistar: crate::TA_S_CDLDOJISTAR,
+    cdldragonflydoji: crate::TA_S_CDLDRAGONFLYDOJI,
+    cdlengulfing: crate::TA_S_CDLENGULFING,
+    cdleveningdojistar: crate::TA_S_CDLEVENINGDOJISTAR,
+    cdleveningstar: crate::TA_S_CDLEVENINGSTAR,
+    cdlgapsidesidewhite: crate::TA_S_CDLGAPSIDESIDEWHITE,
+    cdlgravestonedoji: crate::TA_S_CDLGRAVESTONEDOJI,
+    cdlhammer: crate::TA_S_CDLHAMMER,
+    cdlhangingman: crate::TA_S_CDLHANGINGMAN,
+    cdlharami: crate::TA_S_CDLHARAMI,
+    cdlharamicross: crate::TA_S_CDLHARAMICROSS,
+    cdlhighwave: crate::TA_S_CDLHIGHWAVE,
+    cdlhikkake: crate::TA_S_CDLHIKKAKE,
+    cdlhikkakemod: crate::TA_S_CDLHIKKAKEMOD,
+    cdlhomingpigeon: crate::TA_S_CDLHOMINGPIGEON,
+    cdlidentical3crows: crate::TA_S_CDLIDENTICAL3CROWS,
+    cdlinneck: crate::TA_S_CDLINNECK,
+    cdlinvertedhammer: crate::TA_S_CDLINVERTEDHAMMER,
+    cdlkicking: crate::TA_S_CDLKICKING,
+    cdlkickingbylength: crate::TA_S_CDLKICKINGBYLENGTH,
+    cdlladderbottom: crate::TA_S_CDLLADDERBOTTOM,
+    cdllongleggeddoji: crate::TA_S_CDLLONGLEGGEDDOJI,
+    cdllongline: crate::TA_S_CDLLONGLINE,
+    cdlmarubozu: crate::TA_S_CDLMARUBOZU,
+    cdlmatchinglow: crate::TA_S_CDLMATCHINGLOW,
+    cdlmathold: crate::TA_S_CDLMATHOLD,
+    cdlmorningdojistar: crate::TA_S_CDLMORNINGDOJISTAR,
+    cdlmorningstar: crate::TA_S_CDLMORNINGSTAR,
+    cdlonneck: crate::TA_S_CDLONNECK,
+    cdlpiercing: crate::TA_S_CDLPIERCING,
+    cdlrickshawman: crate::TA_S_CDLRICKSHAWMAN,
+    cdlrisefall3methods: crate::TA_S_CDLRISEFALL3METHODS,
+    cdlseparatinglines: crate::TA_S_CDLSEPARATINGLINES,
+    cdlshootingstar: crate::TA_S_CDLSHOOTINGSTAR,
+    cdlshortline: crate::TA_S_CDLSHORTLINE,
+    cdlspinningtop: crate::TA_S_CDLSPINNINGTOP,
+    cdlstalledpattern: crate::TA_S_CDLSTALLEDPATTERN,
+    cdlsticksandwich: crate::TA_S_CDLSTICKSANDWICH,
+    cdltakuri: crate::TA_S_CDLTAKURI,
+    cdltasukigap: crate::TA_S_CDLTASUKIGAP,
+    cdlthrusting: crate::TA_S_CDLTHRUSTING,
+    cdltristar: crate::TA_S_CDLTRISTAR,
+    cdlunique3river: crate::TA_S_CDLUNIQUE3RIVER,
+    cdlupsidegap2crows: crate::TA_S_CDLUPSIDEGAP2CROWS,
+    cdlxsidegap3methods: crate::TA_S_CDLXSIDEGAP3METHODS,
+};
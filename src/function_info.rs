@@ -0,0 +1,349 @@
+use std::ffi::CString;
+use std::ptr;
+use std::slice;
+
+use crate::abstract_function::{price_series, snake_case, text};
+use crate::talib::{self, check};
+use crate::{AbstractFunction, TaError};
+
+///
+/// Description of a TA-Lib function, as the C library reports it through `ta_abstract.h`: what
+/// it computes, the series it takes, its options with their ranges and defaults, and its outputs.
+///
+/// Names are those of [`AbstractFunction::call`], the catalog is enough to build a form for any
+/// function and run it. With the `serde` feature the catalog can be exported, to JSON for example.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::{AbstractFunction, FunctionInfo};
+///
+/// for function in FunctionInfo::all().unwrap() {
+///     println!("{} ({}): {}", function.name, function.group, function.hint);
+/// }
+/// let macd = AbstractFunction::by_name("MACD").unwrap().info().unwrap();
+/// assert_eq!(macd.opt_inputs[0].name, "fast_period");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionInfo {
+    /// TA-Lib name, such as `"MACD"`
+    pub name: String,
+    /// Group of the function, such as `"Momentum Indicators"`
+    pub group: String,
+    /// Short description, such as `"Moving Average Convergence/Divergence"`
+    pub hint: String,
+    pub inputs: Vec<InputInfo>,
+    pub opt_inputs: Vec<OptInputInfo>,
+    pub outputs: Vec<OutputInfo>,
+}
+
+/// Input of a [`FunctionInfo`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputInfo {
+    /// Parameter name, such as `"price_hlc"` or `"real"`
+    pub name: String,
+    pub kind: InputKind,
+    /// Names of the series [`AbstractFunction::call`] takes for this input, `["high", "low",
+    /// "close"]` for a price input
+    pub series: Vec<String>,
+}
+
+/// Type of an [`InputInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputKind {
+    /// Some of the open, high, low, close, volume and open interest series
+    Price,
+    Real,
+    Integer,
+}
+
+/// Option of a [`FunctionInfo`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptInputInfo {
+    /// Name given to [`AbstractFunction::call`], such as `"fast_period"`
+    pub name: String,
+    /// Label for a user interface, such as `"Fast Period"`
+    pub display_name: String,
+    /// Short description
+    pub hint: String,
+    /// Value used when the option is left out
+    pub default: f64,
+    pub range: OptInputRange,
+}
+
+/// Values accepted by an [`OptInputInfo`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OptInputRange {
+    /// Real from `min` to `max`, usually shown with `precision` decimals
+    Real { min: f64, max: f64, precision: i32 },
+    /// Integer from `min` to `max`
+    Integer {
+        min: crate::TA_Integer,
+        max: crate::TA_Integer,
+    },
+    /// One of the listed reals, each with its label
+    RealList(Vec<(f64, String)>),
+    /// One of the listed integers, each with its label, such as the MA types
+    IntegerList(Vec<(crate::TA_Integer, String)>),
+}
+
+/// Output of a [`FunctionInfo`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputInfo {
+    /// Name in [`crate::AbstractOutputs`], such as `"macd_signal"`
+    pub name: String,
+    pub kind: OutputKind,
+    /// How the output is meant to be drawn or read
+    pub flags: Vec<OutputFlag>,
+}
+
+/// Type of an [`OutputInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputKind {
+    Real,
+    Integer,
+}
+
+/// `TA_OUT_*` flag of an [`OutputInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputFlag {
+    Line,
+    DotLine,
+    DashLine,
+    Dot,
+    Histo,
+    /// Candlestick signal which is either 0 or 100
+    PatternBool,
+    /// Candlestick signal which is 0, 100 or -100
+    PatternBullBear,
+    /// Candlestick signal from -200 to 200
+    PatternStrength,
+    Positive,
+    Negative,
+    Zero,
+    UpperLimit,
+    LowerLimit,
+}
+
+impl OutputFlag {
+    /// Every flag, in the order of the C constants.
+    pub const ALL: [OutputFlag; 13] = [
+        OutputFlag::Line,
+        OutputFlag::DotLine,
+        OutputFlag::DashLine,
+        OutputFlag::Dot,
+        OutputFlag::Histo,
+        OutputFlag::PatternBool,
+        OutputFlag::PatternBullBear,
+        OutputFlag::PatternStrength,
+        OutputFlag::Positive,
+        OutputFlag::Negative,
+        OutputFlag::Zero,
+        OutputFlag::UpperLimit,
+        OutputFlag::LowerLimit,
+    ];
+}
+
+impl From<OutputFlag> for u32 {
+    fn from(flag: OutputFlag) -> Self {
+        match flag {
+            OutputFlag::Line => crate::TA_OUT_LINE,
+            OutputFlag::DotLine => crate::TA_OUT_DOT_LINE,
+            OutputFlag::DashLine => crate::TA_OUT_DASH_LINE,
+            OutputFlag::Dot => crate::TA_OUT_DOT,
+            OutputFlag::Histo => crate::TA_OUT_HISTO,
+            OutputFlag::PatternBool => crate::TA_OUT_PATTERN_BOOL,
+            OutputFlag::PatternBullBear => crate::TA_OUT_PATTERN_BULL_BEAR,
+            OutputFlag::PatternStrength => crate::TA_OUT_PATTERN_STRENGTH,
+            OutputFlag::Positive => crate::TA_OUT_POSITIVE,
+            OutputFlag::Negative => crate::TA_OUT_NEGATIVE,
+            OutputFlag::Zero => crate::TA_OUT_ZERO,
+            OutputFlag::UpperLimit => crate::TA_OUT_UPPER_LIMIT,
+            OutputFlag::LowerLimit => crate::TA_OUT_LOWER_LIMIT,
+        }
+    }
+}
+
+impl FunctionInfo {
+    /// Every function of TA-Lib, group by group in the order of `TA_GroupTableAlloc`.
+    pub fn all() -> Result<Vec<FunctionInfo>, TaError> {
+        let mut functions = Vec::new();
+        for group in StringTable::groups()?.strings() {
+            for name in StringTable::functions(&group)?.strings() {
+                functions.push(AbstractFunction::by_name(&name)?.info()?);
+            }
+        }
+        Ok(functions)
+    }
+}
+
+impl AbstractFunction {
+    /// Describes the function, see [`FunctionInfo`].
+    pub fn info(&self) -> Result<FunctionInfo, TaError> {
+        let inputs = (0..self.info.nbInput)
+            .map(|index| {
+                let input = self.input(index)?;
+                let name = snake_case(text(input.paramName));
+                Ok(match input.type_ {
+                    crate::TA_InputParameterType_TA_Input_Price => InputInfo {
+                        name,
+                        kind: InputKind::Price,
+                        series: price_series(input.flags)
+                            .map(|(_, series)| series.to_string())
+                            .collect(),
+                    },
+                    crate::TA_InputParameterType_TA_Input_Real => InputInfo {
+                        series: vec![name.clone()],
+                        name,
+                        kind: InputKind::Real,
+                    },
+                    _ => InputInfo {
+                        series: vec![name.clone()],
+                        name,
+                        kind: InputKind::Integer,
+                    },
+                })
+            })
+            .collect::<Result<_, TaError>>()?;
+
+        let opt_inputs = (0..self.info.nbOptInput)
+            .map(|index| {
+                let option = self.option(index)?;
+                Ok(OptInputInfo {
+                    name: snake_case(text(option.paramName)),
+                    display_name: text(option.displayName).to_string(),
+                    hint: text(option.hint).to_string(),
+                    default: option.defaultValue,
+                    range: opt_input_range(option)?,
+                })
+            })
+            .collect::<Result<_, TaError>>()?;
+
+        let outputs = (0..self.info.nbOutput)
+            .map(|index| {
+                let output = self.output(index)?;
+                Ok(OutputInfo {
+                    name: snake_case(text(output.paramName)),
+                    kind: match output.type_ {
+                        crate::TA_OutputParameterType_TA_Output_Real => OutputKind::Real,
+                        _ => OutputKind::Integer,
+                    },
+                    flags: OutputFlag::ALL
+                        .into_iter()
+                        .filter(|flag| output.flags as u32 & u32::from(*flag) != 0)
+                        .collect(),
+                })
+            })
+            .collect::<Result<_, TaError>>()?;
+
+        Ok(FunctionInfo {
+            name: self.name().to_string(),
+            group: self.group().to_string(),
+            hint: self.hint().to_string(),
+            inputs,
+            opt_inputs,
+            outputs,
+        })
+    }
+}
+
+// Reads the data set of an option, whose type depends on the type of the option
+fn opt_input_range(option: &crate::TA_OptInputParameterInfo) -> Result<OptInputRange, TaError> {
+    if option.dataSet.is_null() {
+        return Err(TaError::BadObject);
+    }
+    Ok(match option.type_ {
+        crate::TA_OptInputParameterType_TA_OptInput_RealRange => {
+            let range = unsafe { &*(option.dataSet as *const crate::TA_RealRange) };
+            OptInputRange::Real {
+                min: range.min,
+                max: range.max,
+                precision: range.precision,
+            }
+        }
+        crate::TA_OptInputParameterType_TA_OptInput_IntegerRange => {
+            let range = unsafe { &*(option.dataSet as *const crate::TA_IntegerRange) };
+            OptInputRange::Integer {
+                min: range.min,
+                max: range.max,
+            }
+        }
+        crate::TA_OptInputParameterType_TA_OptInput_RealList => {
+            let list = unsafe { &*(option.dataSet as *const crate::TA_RealList) };
+            OptInputRange::RealList(
+                items(list.data, list.nbElement, option)
+                    .iter()
+                    .map(|item| (item.value, text(item.string).to_string()))
+                    .collect(),
+            )
+        }
+        crate::TA_OptInputParameterType_TA_OptInput_IntegerList => {
+            let list = unsafe { &*(option.dataSet as *const crate::TA_IntegerList) };
+            OptInputRange::IntegerList(
+                items(list.data, list.nbElement, option)
+                    .iter()
+                    .map(|item| (item.value, text(item.string).to_string()))
+                    .collect(),
+            )
+        }
+        _ => return Err(TaError::InvalidListType),
+    })
+}
+
+// The `len` items at `data`, borrowed for as long as `_owner`, which holds the memory
+fn items<T>(data: *const T, len: u32, _owner: &impl ?Sized) -> &[T] {
+    if data.is_null() {
+        return &[];
+    }
+    unsafe { slice::from_raw_parts(data, len as usize) }
+}
+
+// Group or function names allocated by the C library, freed on drop
+struct StringTable {
+    table: *mut crate::TA_StringTable,
+    free: unsafe extern "C" fn(*mut crate::TA_StringTable) -> crate::TA_RetCode,
+}
+
+impl StringTable {
+    fn groups() -> Result<StringTable, TaError> {
+        let _lib = talib::handle()?;
+        let mut table = ptr::null_mut();
+        check(unsafe { crate::TA_GroupTableAlloc(&mut table) })?;
+        Ok(StringTable {
+            table,
+            free: crate::TA_GroupTableFree,
+        })
+    }
+
+    fn functions(group: &str) -> Result<StringTable, TaError> {
+        let group = CString::new(group).map_err(|_| TaError::GroupNotFound)?;
+        let _lib = talib::handle()?;
+        let mut table = ptr::null_mut();
+        check(unsafe { crate::TA_FuncTableAlloc(group.as_ptr(), &mut table) })?;
+        Ok(StringTable {
+            table,
+            free: crate::TA_FuncTableFree,
+        })
+    }
+
+    fn strings(&self) -> Vec<String> {
+        let table = unsafe { &*self.table };
+        items(table.string, table.size, self)
+            .iter()
+            .map(|string| text(*string).to_string())
+            .collect()
+    }
+}
+
+impl Drop for StringTable {
+    fn drop(&mut self) {
+        unsafe { (self.free)(self.table) };
+    }
+}
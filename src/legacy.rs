@@ -0,0 +1,453 @@
+//! Panicking variants of the functions in [`crate::wrapper`].
+//!
+//! They keep the signatures the wrappers had before they returned `Result<_, TaError>`, and panic
+//! with the TA-Lib error message when the computation fails. New code should call
+//! [`crate::wrapper`] directly and handle the error.
+use crate::wrapper;
+use crate::MaType;
+use crate::TaError;
+
+fn expect<T>(result: Result<T, TaError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("Could not compute indicator, err: {}", err),
+    }
+}
+
+fn ma_type(value: crate::TA_MAType) -> MaType {
+    expect(MaType::try_from(value))
+}
+
+#[deprecated(note = "use `wrapper::accbands`, which returns a `Result` instead of panicking")]
+pub fn accbands(
+    period: u32,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::accbands(period, high, low, close));
+    (out.upper, out.middle, out.lower, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::accbands`, which returns a `Result` instead of panicking")]
+pub fn s_accbands(
+    period: u32,
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::accbands(period, high, low, close));
+    (out.upper, out.middle, out.lower, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::acos`, which returns a `Result` instead of panicking")]
+pub fn acos(close: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::acos(close))
+}
+
+#[deprecated(note = "use `wrapper::acos`, which returns a `Result` instead of panicking")]
+pub fn s_acos(close: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::acos(close))
+}
+
+#[deprecated(note = "use `wrapper::ad`, which returns a `Result` instead of panicking")]
+pub fn ad(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ad(high, low, close, volume))
+}
+
+#[deprecated(note = "use `wrapper::ad`, which returns a `Result` instead of panicking")]
+pub fn s_ad(
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+    volume: &[f32],
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ad(high, low, close, volume))
+}
+
+#[deprecated(note = "use `wrapper::adosc`, which returns a `Result` instead of panicking")]
+pub fn adosc(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    fastperiod: i32,
+    slowperiod: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::adosc(
+        high, low, close, volume, fastperiod, slowperiod,
+    ))
+}
+
+#[deprecated(note = "use `wrapper::adx`, which returns a `Result` instead of panicking")]
+pub fn adx(period: u32, high: &[f64], low: &[f64], close: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::adx(period, high, low, close))
+}
+
+#[deprecated(note = "use `wrapper::adx`, which returns a `Result` instead of panicking")]
+pub fn s_adx(
+    period: u32,
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::adx(period, high, low, close))
+}
+
+#[deprecated(note = "use `wrapper::apo`, which returns a `Result` instead of panicking")]
+pub fn apo(
+    close: &[f64],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: crate::TA_MAType,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::apo(close, fastperiod, slowperiod, ma_type(matype)))
+}
+
+#[deprecated(note = "use `wrapper::aroonosc`, which returns a `Result` instead of panicking")]
+pub fn aroonosc(high: &[f64], low: &[f64], timeperiod: i32) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::aroonosc(high, low, timeperiod))
+}
+
+#[deprecated(note = "use `wrapper::atr`, which returns a `Result` instead of panicking")]
+pub fn atr(period: u32, high: &[f64], low: &[f64], close: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::atr(period, high, low, close))
+}
+
+#[deprecated(note = "use `wrapper::atr`, which returns a `Result` instead of panicking")]
+pub fn s_atr(
+    period: u32,
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
+    expect(wrapper::atr(period, high, low, close))
+}
+
+#[deprecated(note = "use `wrapper::bbands`, which returns a `Result` instead of panicking")]
+pub fn bbands(
+    period: u32,
+    in_real: &[f64],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: crate::TA_MAType,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::bbands(
+        period,
+        in_real,
+        in_db_dev_up,
+        in_db_dev_down,
+        ma_type(in_ma_type),
+    ));
+    (out.upper, out.middle, out.lower, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::bbands`, which returns a `Result` instead of panicking")]
+pub fn s_bbands(
+    period: u32,
+    in_real: &[f32],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: crate::TA_MAType,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::bbands(
+        period,
+        in_real,
+        in_db_dev_up,
+        in_db_dev_down,
+        ma_type(in_ma_type),
+    ));
+    (out.upper, out.middle, out.lower, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::cci`, which returns a `Result` instead of panicking")]
+pub fn cci(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    timeperiod: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::cci(high, low, close, timeperiod))
+}
+
+#[deprecated(note = "use `wrapper::ma`, which returns a `Result` instead of panicking")]
+pub fn ma(
+    period: u32,
+    optInMAType: crate::TA_MAType,
+    close: &[f64],
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ma(period, ma_type(optInMAType), close))
+}
+
+#[deprecated(note = "use `wrapper::ma`, which returns a `Result` instead of panicking")]
+pub fn s_ma(
+    period: u32,
+    optInMAType: crate::TA_MAType,
+    close: &[f32],
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ma(period, ma_type(optInMAType), close))
+}
+
+#[deprecated(note = "use `wrapper::macd`, which returns a `Result` instead of panicking")]
+pub fn macd(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[f64],
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::macd(
+        fast_period,
+        slow_period,
+        signal_period,
+        close,
+    ));
+    (out.macd, out.signal, out.hist, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::macd`, which returns a `Result` instead of panicking")]
+pub fn s_macd(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[f32],
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::macd(
+        fast_period,
+        slow_period,
+        signal_period,
+        close,
+    ));
+    (out.macd, out.signal, out.hist, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::mama`, which returns a `Result` instead of panicking")]
+pub fn mama(
+    in_real: &[f64],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::mama(in_real, in_fast_limit, in_flow_limit));
+    (out.mama, out.fama, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::mama`, which returns a `Result` instead of panicking")]
+pub fn s_mama(
+    in_real: &[f32],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::mama(in_real, in_fast_limit, in_flow_limit));
+    (out.mama, out.fama, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::mfi`, which returns a `Result` instead of panicking")]
+pub fn mfi(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    timeperiod: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::mfi(high, low, close, volume, timeperiod))
+}
+
+#[deprecated(note = "use `wrapper::minus_dm`, which returns a `Result` instead of panicking")]
+pub fn minus_dm(period: u32, high: &[f64], low: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::minus_dm(period, high, low))
+}
+
+#[deprecated(note = "use `wrapper::minus_dm`, which returns a `Result` instead of panicking")]
+pub fn s_minus_dm(period: u32, high: &[f32], low: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::minus_dm(period, high, low))
+}
+
+#[deprecated(note = "use `wrapper::natr`, which returns a `Result` instead of panicking")]
+pub fn natr(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    timeperiod: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::natr(high, low, close, timeperiod))
+}
+
+#[deprecated(note = "use `wrapper::obv`, which returns a `Result` instead of panicking")]
+pub fn obv(close: &[f64], volume: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::obv(close, volume))
+}
+
+#[deprecated(note = "use `wrapper::obv`, which returns a `Result` instead of panicking")]
+pub fn s_obv(close: &[f32], volume: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::obv(close, volume))
+}
+
+#[deprecated(note = "use `wrapper::plus_dm`, which returns a `Result` instead of panicking")]
+pub fn plus_dm(period: u32, high: &[f64], low: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::plus_dm(period, high, low))
+}
+
+#[deprecated(note = "use `wrapper::plus_dm`, which returns a `Result` instead of panicking")]
+pub fn s_plus_dm(period: u32, high: &[f32], low: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::plus_dm(period, high, low))
+}
+
+#[deprecated(note = "use `wrapper::ppo`, which returns a `Result` instead of panicking")]
+pub fn ppo(
+    close: &[f64],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: crate::TA_MAType,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ppo(close, fastperiod, slowperiod, ma_type(matype)))
+}
+
+#[deprecated(note = "use `wrapper::rsi`, which returns a `Result` instead of panicking")]
+pub fn rsi(period: u32, close_prices: &[f64]) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
+    expect(wrapper::rsi(period, close_prices))
+}
+
+#[deprecated(note = "use `wrapper::rsi`, which returns a `Result` instead of panicking")]
+pub fn s_rsi(period: u32, close_prices: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::rsi(period, close_prices))
+}
+
+#[deprecated(note = "use `wrapper::sma`, which returns a `Result` instead of panicking")]
+pub fn sma(period: u32, close_prices: &[f64]) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
+    expect(wrapper::sma(period, close_prices))
+}
+
+#[deprecated(note = "use `wrapper::sma`, which returns a `Result` instead of panicking")]
+pub fn s_sma(period: u32, close_prices: &[f32]) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
+    expect(wrapper::sma(period, close_prices))
+}
+
+#[deprecated(note = "use `wrapper::stoch`, which returns a `Result` instead of panicking")]
+#[allow(clippy::too_many_arguments)]
+pub fn stoch(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: crate::TA_MAType,
+    slowd_period: u32,
+    optInSlowD_MAType: crate::TA_MAType,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::stoch(
+        fastk_period,
+        slowk_period,
+        ma_type(optInSlowK_MAType),
+        slowd_period,
+        ma_type(optInSlowD_MAType),
+        high,
+        low,
+        close,
+    ));
+    (out.slow_k, out.slow_d, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::stoch`, which returns a `Result` instead of panicking")]
+#[allow(clippy::too_many_arguments)]
+pub fn s_stoch(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: crate::TA_MAType,
+    slowd_period: u32,
+    optInSlowD_MAType: crate::TA_MAType,
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::stoch(
+        fastk_period,
+        slowk_period,
+        ma_type(optInSlowK_MAType),
+        slowd_period,
+        ma_type(optInSlowD_MAType),
+        high,
+        low,
+        close,
+    ));
+    (out.slow_k, out.slow_d, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::stochrsi`, which returns a `Result` instead of panicking")]
+pub fn stochrsi(
+    close: &[f64],
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: crate::TA_MAType,
+) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
+    let out = expect(wrapper::stochrsi(
+        close,
+        timeperiod,
+        fastk_period,
+        fastd_period,
+        ma_type(fastd_matype),
+    ));
+    (out.fast_k, out.fast_d, out.begin)
+}
+
+#[deprecated(note = "use `wrapper::ultosc`, which returns a `Result` instead of panicking")]
+pub fn ultosc(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ultosc(
+        high,
+        low,
+        close,
+        timeperiod1,
+        timeperiod2,
+        timeperiod3,
+    ))
+}
+
+#[deprecated(note = "use `wrapper::ultosc`, which returns a `Result` instead of panicking")]
+pub fn s_ultosc(
+    high: &[f32],
+    low: &[f32],
+    close: &[f32],
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::ultosc(
+        high,
+        low,
+        close,
+        timeperiod1,
+        timeperiod2,
+        timeperiod3,
+    ))
+}
+
+#[deprecated(note = "use `wrapper::willr`, which returns a `Result` instead of panicking")]
+pub fn willr(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    timeperiod: i32,
+) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::willr(high, low, close, timeperiod))
+}
+
+#[deprecated(note = "use `wrapper::wma`, which returns a `Result` instead of panicking")]
+pub fn wma(period: u32, close: &[f64]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::wma(period, close))
+}
+
+#[deprecated(note = "use `wrapper::wma`, which returns a `Result` instead of panicking")]
+pub fn s_wma(period: u32, close: &[f32]) -> (Vec<f64>, crate::TA_Integer) {
+    expect(wrapper::wma(period, close))
+}
@@ -5,4 +5,32 @@
 // This matches bindgen::Builder output
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+mod abstract_function;
+mod candle;
+mod candle_settings;
+mod compatibility;
+mod error;
+mod float;
+mod function_info;
+pub mod legacy;
+mod ma_type;
+pub mod stream;
+mod talib;
+mod unstable;
 pub mod wrapper;
+
+pub use abstract_function::{AbstractFunction, AbstractOutputs, AbstractValues, OptionValue};
+pub use candle::CandleSignal;
+pub use candle_settings::{
+    CandlePresets, CandleSetting, CandleSettingType, CandleSettings, RangeType,
+};
+pub use compatibility::Compatibility;
+pub use error::TaError;
+pub use float::TaFloat;
+pub use function_info::{
+    FunctionInfo, InputInfo, InputKind, OptInputInfo, OptInputRange, OutputFlag, OutputInfo,
+    OutputKind,
+};
+pub use ma_type::MaType;
+pub use talib::TaLib;
+pub use unstable::{UnstableFunc, UnstablePeriod};
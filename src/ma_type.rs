@@ -0,0 +1,117 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::TaError;
+
+///
+/// Moving average used by the wrappers taking an MA type (`ma`, `bbands`, `stoch`, `macdext`...).
+///
+/// Converts to the `TA_MAType_*` constants of the C library. It parses from and displays as the
+/// TA-Lib names (`"SMA"`, `"EMA"`...), parsing ignores case.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::MaType;
+///
+/// let ma_type: MaType = "ema".parse().unwrap();
+/// assert_eq!(ma_type, MaType::Ema);
+/// assert_eq!(ma_type.to_string(), "EMA");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MaType {
+    /// Simple moving average
+    #[default]
+    Sma,
+    /// Exponential moving average
+    Ema,
+    /// Weighted moving average
+    Wma,
+    /// Double exponential moving average
+    Dema,
+    /// Triple exponential moving average
+    Tema,
+    /// Triangular moving average
+    Trima,
+    /// Kaufman adaptive moving average
+    Kama,
+    /// MESA adaptive moving average
+    Mama,
+    /// Triple exponential moving average (T3)
+    T3,
+}
+
+impl MaType {
+    /// Every MA type, in the order of the C enum.
+    pub const ALL: [MaType; 9] = [
+        MaType::Sma,
+        MaType::Ema,
+        MaType::Wma,
+        MaType::Dema,
+        MaType::Tema,
+        MaType::Trima,
+        MaType::Kama,
+        MaType::Mama,
+        MaType::T3,
+    ];
+
+    /// The TA-Lib name of the moving average.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaType::Sma => "SMA",
+            MaType::Ema => "EMA",
+            MaType::Wma => "WMA",
+            MaType::Dema => "DEMA",
+            MaType::Tema => "TEMA",
+            MaType::Trima => "TRIMA",
+            MaType::Kama => "KAMA",
+            MaType::Mama => "MAMA",
+            MaType::T3 => "T3",
+        }
+    }
+}
+
+impl From<MaType> for crate::TA_MAType {
+    fn from(ma_type: MaType) -> Self {
+        match ma_type {
+            MaType::Sma => crate::TA_MAType_TA_MAType_SMA,
+            MaType::Ema => crate::TA_MAType_TA_MAType_EMA,
+            MaType::Wma => crate::TA_MAType_TA_MAType_WMA,
+            MaType::Dema => crate::TA_MAType_TA_MAType_DEMA,
+            MaType::Tema => crate::TA_MAType_TA_MAType_TEMA,
+            MaType::Trima => crate::TA_MAType_TA_MAType_TRIMA,
+            MaType::Kama => crate::TA_MAType_TA_MAType_KAMA,
+            MaType::Mama => crate::TA_MAType_TA_MAType_MAMA,
+            MaType::T3 => crate::TA_MAType_TA_MAType_T3,
+        }
+    }
+}
+
+/// Fails with [`TaError::BadParam`] for a value that is not a `TA_MAType_*` constant.
+impl TryFrom<crate::TA_MAType> for MaType {
+    type Error = TaError;
+
+    fn try_from(value: crate::TA_MAType) -> Result<Self, Self::Error> {
+        MaType::ALL
+            .into_iter()
+            .find(|ma_type| crate::TA_MAType::from(*ma_type) == value)
+            .ok_or(TaError::BadParam)
+    }
+}
+
+/// Fails with [`TaError::BadParam`] for a name that is not one of the TA-Lib ones.
+impl FromStr for MaType {
+    type Err = TaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MaType::ALL
+            .into_iter()
+            .find(|ma_type| ma_type.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or(TaError::BadParam)
+    }
+}
+
+impl fmt::Display for MaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,828 @@
+//! Streaming counterparts of some wrappers, fed one bar at a time.
+//!
+//! A stream keeps the state the C function carries from one bar to the next, so `next` costs
+//! O(period) at most instead of recomputing the whole series. It returns `None` while it warms up,
+//! exactly as many times as the begin index returned by the batch wrapper, then one output per
+//! bar. The outputs are bit-identical to what the batch wrapper returns for the same inputs: the
+//! streams repeat the floating point operations of the C code in the same order.
+//!
+//! Streams read the unstable periods (see [`crate::UnstablePeriod`]) when they are created and
+//! compute in the default [`crate::Compatibility`] mode.
+//!
+//! With the `serde` feature, streams serialize their whole state, so a service can checkpoint
+//! them (with `serde_json`, or a compact binary format such as `rmp-serde`) and resume without
+//! replaying history. Snapshots are tagged with the stream and [`SNAPSHOT_VERSION`]: restoring
+//! a snapshot of another stream, or from a crate version that kept a different state, fails
+//! with an error naming both instead of producing wrong values. The parameters of a restored
+//! stream are checked like the ones given to `new`.
+//!
+//! #Sample
+//! ```
+//! use rust_ta_lib::stream::SmaStream;
+//!
+//! let close_prices: Vec<f64> = vec![1.087010, 1.087120, 1.087080, 1.087170, 1.087110];
+//! let mut sma = SmaStream::new(3).unwrap();
+//! let values: Vec<Option<f64>> = close_prices.iter().map(|close| sma.next(*close)).collect();
+//! let (batch, begin) = rust_ta_lib::wrapper::sma(3, &close_prices).unwrap();
+//! assert_eq!(values[..begin as usize], [None, None]);
+//! assert_eq!(values[begin as usize..], batch.into_iter().map(Some).collect::<Vec<_>>());
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::wrapper;
+use crate::{MaType, TaError, UnstableFunc, UnstablePeriod};
+
+// TA_IS_ZERO and TA_IS_ZERO_OR_NEG of the C library
+fn is_zero(value: f64) -> bool {
+    -0.00000001 < value && value < 0.00000001
+}
+
+fn is_zero_or_neg(value: f64) -> bool {
+    value < 0.00000001
+}
+
+/// Version of the stream snapshots, bumped whenever the state kept by a stream changes
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+trait Named {
+    const NAME: &'static str;
+}
+
+/// First field of every stream, written as `"<stream> v<version>"` in snapshots so that a
+/// snapshot of another stream, or from a crate with another snapshot version, is rejected.
+struct Tag<S>(PhantomData<fn() -> S>);
+
+impl<S> Tag<S> {
+    fn new() -> Tag<S> {
+        Tag(PhantomData)
+    }
+}
+
+impl<S> Clone for Tag<S> {
+    fn clone(&self) -> Self {
+        Tag::new()
+    }
+}
+
+impl<S: Named> fmt::Debug for Tag<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} v{}", S::NAME, SNAPSHOT_VERSION)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: Named> serde::Serialize for Tag<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(&format_args!("{:?}", self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Named> serde::Deserialize<'de> for Tag<S> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let found = String::deserialize(deserializer)?;
+        let tag = Tag::new();
+        let expected = format!("{:?}", tag);
+        if found != expected {
+            return Err(serde::de::Error::custom(format_args!(
+                "snapshot of {found}, expected a snapshot of {expected}"
+            )));
+        }
+        Ok(tag)
+    }
+}
+
+// The streams derive their serde code as inherent functions (`serde(remote = "Self")`), the
+// trait impls run it and then check the restored parameters like `new` does: a snapshot edited
+// by hand must not make `next` divide by zero or overflow.
+#[cfg(feature = "serde")]
+macro_rules! checked_snapshot {
+    ($($stream:ident),*) => {$(
+        impl serde::Serialize for $stream {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                serializer: Ser,
+            ) -> Result<Ser::Ok, Ser::Error> {
+                $stream::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $stream {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let stream = $stream::deserialize(deserializer)?;
+                stream.check().map_err(serde::de::Error::custom)?;
+                Ok(stream)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "serde")]
+checked_snapshot!(
+    SmaStream,
+    EmaStream,
+    RsiStream,
+    MacdStream,
+    AtrStream,
+    BBandsStream,
+    StochStream
+);
+
+// Periods kept as `usize`, out of range once they do not fit the C library
+#[cfg(feature = "serde")]
+fn period(period: usize) -> u32 {
+    u32::try_from(period).unwrap_or(u32::MAX)
+}
+
+#[cfg(feature = "serde")]
+impl SmaStream {
+    fn check(&self) -> Result<(), TaError> {
+        wrapper::sma_lookback(period(self.period))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl EmaStream {
+    fn check(&self) -> Result<(), TaError> {
+        wrapper::ema_lookback(self.period)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RsiStream {
+    fn check(&self) -> Result<(), TaError> {
+        wrapper::rsi_lookback(self.period)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl MacdStream {
+    fn check(&self) -> Result<(), TaError> {
+        wrapper::macd_lookback(self.fast.period, self.slow.period, self.signal.period)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl AtrStream {
+    fn check(&self) -> Result<(), TaError> {
+        wrapper::atr_lookback(self.period)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl BBandsStream {
+    fn check(&self) -> Result<(), TaError> {
+        let (period, ma_type) = self.average.params();
+        // the average has to be the one `new` would have built
+        if period as usize != self.period || self.simple != (ma_type == MaType::Sma) {
+            return Err(TaError::BadParam);
+        }
+        wrapper::bbands_lookback(period, self.dev_up, self.dev_down, ma_type)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl StochStream {
+    fn check(&self) -> Result<(), TaError> {
+        let (slowk_period, slowk_ma_type) = self.slow_k.params();
+        let (slowd_period, slowd_ma_type) = self.slow_d.params();
+        wrapper::stoch_lookback(
+            period(self.fastk_period),
+            slowk_period,
+            slowk_ma_type,
+            slowd_period,
+            slowd_ma_type,
+        )?;
+        Ok(())
+    }
+}
+
+impl Named for SmaStream {
+    const NAME: &'static str = "SmaStream";
+}
+
+impl Named for EmaStream {
+    const NAME: &'static str = "EmaStream";
+}
+
+impl Named for RsiStream {
+    const NAME: &'static str = "RsiStream";
+}
+
+impl Named for MacdStream {
+    const NAME: &'static str = "MacdStream";
+}
+
+impl Named for AtrStream {
+    const NAME: &'static str = "AtrStream";
+}
+
+impl Named for BBandsStream {
+    const NAME: &'static str = "BBandsStream";
+}
+
+impl Named for StochStream {
+    const NAME: &'static str = "StochStream";
+}
+
+/// High, low and close of one bar, the input of [`AtrStream`] and [`StochStream`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hlc {
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// Counts down the outputs hidden by an unstable period.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Skip(u32);
+
+impl Skip {
+    fn unstable(func: UnstableFunc) -> Skip {
+        Skip(UnstablePeriod::get(func))
+    }
+
+    fn filter(&mut self, value: Option<f64>) -> Option<f64> {
+        match value {
+            Some(_) if self.0 > 0 => {
+                self.0 -= 1;
+                None
+            }
+            value => value,
+        }
+    }
+}
+
+/// Streaming [`wrapper::sma`]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct SmaStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<SmaStream>,
+    period: usize,
+    window: VecDeque<f64>,
+    total: f64,
+}
+
+impl SmaStream {
+    /// Fails like [`wrapper::sma`] for an out of range `period`.
+    pub fn new(period: u32) -> Result<SmaStream, TaError> {
+        wrapper::sma_lookback(period)?;
+        Ok(SmaStream::unchecked(period))
+    }
+
+    fn unchecked(period: u32) -> SmaStream {
+        SmaStream {
+            _snapshot: Tag::new(),
+            period: period as usize,
+            window: VecDeque::with_capacity(period as usize),
+            total: 0.0,
+        }
+    }
+
+    /// Adds one value, returns the average of the last `period` values once there are enough.
+    pub fn next(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        self.total += value;
+        if self.window.len() < self.period {
+            return None;
+        }
+        let total = self.total;
+        self.total -= self.window.pop_front().unwrap_or_default();
+        Some(total / self.period as f64)
+    }
+}
+
+/// Streaming [`wrapper::ema`]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct EmaStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<EmaStream>,
+    period: u32,
+    k: f64,
+    count: u32,
+    prev: f64,
+    skip: Skip,
+}
+
+impl EmaStream {
+    /// Fails like [`wrapper::ema`] for an out of range `timeperiod`.
+    pub fn new(timeperiod: u32) -> Result<EmaStream, TaError> {
+        wrapper::ema_lookback(timeperiod)?;
+        Ok(EmaStream::unchecked(timeperiod))
+    }
+
+    fn unchecked(period: u32) -> EmaStream {
+        EmaStream {
+            _snapshot: Tag::new(),
+            period,
+            k: 2.0 / (period + 1) as f64,
+            count: 0,
+            prev: 0.0,
+            skip: Skip::unstable(UnstableFunc::Ema),
+        }
+    }
+
+    /// Adds one value, returns the average once the first `timeperiod` values (plus the unstable
+    /// period) are in. The average starts as the simple average of the first values.
+    pub fn next(&mut self, value: f64) -> Option<f64> {
+        let out = if self.count < self.period {
+            // the seed is summed in `prev`
+            self.prev += value;
+            self.count += 1;
+            if self.count < self.period {
+                return None;
+            }
+            self.prev /= self.period as f64;
+            self.prev
+        } else {
+            self.prev = ((value - self.prev) * self.k) + self.prev;
+            self.prev
+        };
+        self.skip.filter(Some(out))
+    }
+}
+
+/// Moving average of the functions taking an MA type, with the shortcuts of `TA_MA`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum MaStream {
+    Copy,
+    Sma(#[cfg_attr(feature = "serde", serde(with = "SmaStream"))] SmaStream),
+    Ema(#[cfg_attr(feature = "serde", serde(with = "EmaStream"))] EmaStream),
+}
+
+impl MaStream {
+    /// Only simple and exponential averages have a stream, others are `TaError::NotSupported`.
+    fn new(period: u32, ma_type: MaType) -> Result<MaStream, TaError> {
+        match ma_type {
+            _ if period == 1 => Ok(MaStream::Copy),
+            MaType::Sma => Ok(MaStream::Sma(SmaStream::unchecked(period))),
+            MaType::Ema => Ok(MaStream::Ema(EmaStream::unchecked(period))),
+            _ => Err(TaError::NotSupported),
+        }
+    }
+
+    /// Period and MA type the average was built for, a copy stands for any type of period 1.
+    #[cfg(feature = "serde")]
+    fn params(&self) -> (u32, MaType) {
+        match self {
+            MaStream::Copy => (1, MaType::Sma),
+            MaStream::Sma(sma) => (period(sma.period), MaType::Sma),
+            MaStream::Ema(ema) => (ema.period, MaType::Ema),
+        }
+    }
+
+    fn next(&mut self, value: f64) -> Option<f64> {
+        match self {
+            MaStream::Copy => Some(value),
+            MaStream::Sma(sma) => sma.next(value),
+            MaStream::Ema(ema) => ema.next(value),
+        }
+    }
+}
+
+/// Streaming [`wrapper::rsi`]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct RsiStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<RsiStream>,
+    period: u32,
+    prev_value: Option<f64>,
+    count: u32,
+    prev_gain: f64,
+    prev_loss: f64,
+    skip: Skip,
+}
+
+impl RsiStream {
+    /// Fails like [`wrapper::rsi`] for an out of range `period`.
+    pub fn new(period: u32) -> Result<RsiStream, TaError> {
+        wrapper::rsi_lookback(period)?;
+        Ok(RsiStream {
+            _snapshot: Tag::new(),
+            period,
+            prev_value: None,
+            count: 0,
+            prev_gain: 0.0,
+            prev_loss: 0.0,
+            skip: Skip::unstable(UnstableFunc::Rsi),
+        })
+    }
+
+    /// Adds one value, returns the RSI once `period` changes (plus the unstable period) are in.
+    pub fn next(&mut self, value: f64) -> Option<f64> {
+        let prev_value = self.prev_value.replace(value)?;
+        let change = value - prev_value;
+        let period = self.period as f64;
+        if self.count < self.period {
+            self.count += 1;
+            if change < 0.0 {
+                self.prev_loss -= change;
+            } else {
+                self.prev_gain += change;
+            }
+            if self.count < self.period {
+                return None;
+            }
+        } else {
+            self.prev_loss *= (self.period - 1) as f64;
+            self.prev_gain *= (self.period - 1) as f64;
+            if change < 0.0 {
+                self.prev_loss -= change;
+            } else {
+                self.prev_gain += change;
+            }
+        }
+        self.prev_loss /= period;
+        self.prev_gain /= period;
+        let total = self.prev_gain + self.prev_loss;
+        let rsi = if !is_zero(total) {
+            100.0 * (self.prev_gain / total)
+        } else {
+            0.0
+        };
+        self.skip.filter(Some(rsi))
+    }
+}
+
+/// Output of [`MacdStream`] for one bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MacdValue {
+    pub macd: f64,
+    pub signal: f64,
+    pub hist: f64,
+}
+
+/// Streaming [`wrapper::macd`]
+///
+/// Like TA-Lib, the fast average is seeded on the values right before the first output of the
+/// slow one, not on the first values of the input.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct MacdStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<MacdStream>,
+    // inputs left before the fast average starts
+    fast_delay: u32,
+    // checked as a whole, the signal line may have a period of 1
+    #[cfg_attr(feature = "serde", serde(with = "EmaStream"))]
+    fast: EmaStream,
+    #[cfg_attr(feature = "serde", serde(with = "EmaStream"))]
+    slow: EmaStream,
+    #[cfg_attr(feature = "serde", serde(with = "EmaStream"))]
+    signal: EmaStream,
+}
+
+impl MacdStream {
+    /// Fails like [`wrapper::macd`] for out of range periods.
+    pub fn new(
+        fast_period: u32,
+        slow_period: u32,
+        signal_period: u32,
+    ) -> Result<MacdStream, TaError> {
+        wrapper::macd_lookback(fast_period, slow_period, signal_period)?;
+        let (fast_period, slow_period) = if slow_period < fast_period {
+            (slow_period, fast_period)
+        } else {
+            (fast_period, slow_period)
+        };
+        Ok(MacdStream {
+            _snapshot: Tag::new(),
+            fast_delay: slow_period - fast_period,
+            fast: EmaStream::unchecked(fast_period),
+            slow: EmaStream::unchecked(slow_period),
+            signal: EmaStream::unchecked(signal_period),
+        })
+    }
+
+    /// Adds one value, returns the MACD, its signal line and their difference once the slow
+    /// average and the signal line are both warmed up.
+    pub fn next(&mut self, value: f64) -> Option<MacdValue> {
+        let slow = self.slow.next(value);
+        let fast = if self.fast_delay > 0 {
+            self.fast_delay -= 1;
+            None
+        } else {
+            self.fast.next(value)
+        };
+        let macd = fast? - slow?;
+        let signal = self.signal.next(macd)?;
+        Some(MacdValue {
+            macd,
+            signal,
+            hist: macd - signal,
+        })
+    }
+}
+
+/// Streaming [`wrapper::atr`]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct AtrStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<AtrStream>,
+    period: u32,
+    prev_close: Option<f64>,
+    count: u32,
+    prev_atr: f64,
+    skip: Skip,
+}
+
+impl AtrStream {
+    /// Fails like [`wrapper::atr`] for an out of range `period`.
+    pub fn new(period: u32) -> Result<AtrStream, TaError> {
+        wrapper::atr_lookback(period)?;
+        Ok(AtrStream {
+            _snapshot: Tag::new(),
+            period,
+            prev_close: None,
+            count: 0,
+            prev_atr: 0.0,
+            skip: Skip::unstable(UnstableFunc::Atr),
+        })
+    }
+
+    /// Adds one bar, returns the average true range once `period` true ranges (plus the
+    /// unstable period) are in.
+    pub fn next(&mut self, bar: Hlc) -> Option<f64> {
+        let prev_close = self.prev_close.replace(bar.close)?;
+        let true_range = true_range(bar, prev_close);
+        let atr = if self.period <= 1 {
+            // TA_ATR is TA_TRANGE then
+            true_range
+        } else if self.count < self.period {
+            // the first average is summed in `prev_atr`
+            self.prev_atr += true_range;
+            self.count += 1;
+            if self.count < self.period {
+                return None;
+            }
+            self.prev_atr /= self.period as f64;
+            self.prev_atr
+        } else {
+            self.prev_atr *= (self.period - 1) as f64;
+            self.prev_atr += true_range;
+            self.prev_atr /= self.period as f64;
+            self.prev_atr
+        };
+        self.skip.filter(Some(atr))
+    }
+}
+
+// TA_TRANGE for one bar
+fn true_range(bar: Hlc, prev_close: f64) -> f64 {
+    let mut greatest = bar.high - bar.low;
+    let high_gap = (prev_close - bar.high).abs();
+    if high_gap > greatest {
+        greatest = high_gap;
+    }
+    let low_gap = (prev_close - bar.low).abs();
+    if low_gap > greatest {
+        greatest = low_gap;
+    }
+    greatest
+}
+
+/// Output of [`BBandsStream`] for one bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BBandsValue {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+}
+
+/// Running sums of a window, for the standard deviation of [`BBandsStream`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Deviation {
+    window: VecDeque<f64>,
+    total: f64,
+    total_squares: f64,
+    // values to ignore first, the variance starts with the average when it has an unstable period
+    delay: u32,
+}
+
+impl Deviation {
+    fn new(period: u32, delay: u32) -> Deviation {
+        Deviation {
+            window: VecDeque::with_capacity(period as usize),
+            total: 0.0,
+            total_squares: 0.0,
+            delay,
+        }
+    }
+
+    /// `TA_INT_stddev_using_precalc_ma`, used by TA-Lib next to a simple average.
+    fn around(&mut self, value: f64, period: usize, average: Option<f64>) -> Option<f64> {
+        self.window.push_back(value);
+        self.total_squares += value * value;
+        let average = average?;
+        let mut mean_squares = self.total_squares / period as f64;
+        let trailing = self.window.pop_front().unwrap_or_default();
+        self.total_squares -= trailing * trailing;
+        mean_squares -= average * average;
+        Some(root(mean_squares))
+    }
+
+    /// `TA_STDDEV`, used by TA-Lib next to the other averages.
+    fn of(&mut self, value: f64, period: usize) -> Option<f64> {
+        if self.delay > 0 {
+            self.delay -= 1;
+            return None;
+        }
+        self.window.push_back(value);
+        self.total += value;
+        self.total_squares += value * value;
+        if self.window.len() < period {
+            return None;
+        }
+        let mean = self.total / period as f64;
+        let mean_squares = self.total_squares / period as f64;
+        let trailing = self.window.pop_front().unwrap_or_default();
+        self.total -= trailing;
+        self.total_squares -= trailing * trailing;
+        Some(root(mean_squares - (mean * mean)))
+    }
+}
+
+fn root(variance: f64) -> f64 {
+    if !is_zero_or_neg(variance) {
+        variance.sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// Streaming [`wrapper::bbands`], for simple and exponential moving averages
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct BBandsStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<BBandsStream>,
+    period: usize,
+    dev_up: f64,
+    dev_down: f64,
+    simple: bool,
+    average: MaStream,
+    deviation: Deviation,
+}
+
+impl BBandsStream {
+    /// Fails like [`wrapper::bbands`] for out of range parameters, and with
+    /// [`TaError::NotSupported`] for an MA type other than `Sma` and `Ema`.
+    pub fn new(
+        period: u32,
+        in_db_dev_up: f64,
+        in_db_dev_down: f64,
+        in_ma_type: MaType,
+    ) -> Result<BBandsStream, TaError> {
+        wrapper::bbands_lookback(period, in_db_dev_up, in_db_dev_down, in_ma_type)?;
+        let delay = match in_ma_type {
+            MaType::Ema => UnstablePeriod::get(UnstableFunc::Ema),
+            _ => 0,
+        };
+        Ok(BBandsStream {
+            _snapshot: Tag::new(),
+            period: period as usize,
+            dev_up: in_db_dev_up,
+            dev_down: in_db_dev_down,
+            simple: in_ma_type == MaType::Sma,
+            average: MaStream::new(period, in_ma_type)?,
+            deviation: Deviation::new(period, delay),
+        })
+    }
+
+    /// Adds one value, returns the bands once the moving average is warmed up.
+    pub fn next(&mut self, value: f64) -> Option<BBandsValue> {
+        let average = self.average.next(value);
+        let deviation = if self.simple {
+            self.deviation.around(value, self.period, average)
+        } else {
+            self.deviation.of(value, self.period)
+        };
+        let (middle, deviation) = (average?, deviation?);
+        Some(BBandsValue {
+            upper: middle + (deviation * self.dev_up),
+            middle,
+            lower: middle - (deviation * self.dev_down),
+        })
+    }
+}
+
+/// Output of [`StochStream`] for one bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochValue {
+    pub slow_k: f64,
+    pub slow_d: f64,
+}
+
+/// Streaming [`wrapper::stoch`], for simple and exponential moving averages
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct StochStream {
+    #[cfg_attr(feature = "serde", serde(rename = "snapshot"))]
+    _snapshot: Tag<StochStream>,
+    fastk_period: usize,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    slow_k: MaStream,
+    slow_d: MaStream,
+}
+
+impl StochStream {
+    /// Fails like [`wrapper::stoch`] for out of range periods, and with
+    /// [`TaError::NotSupported`] for an MA type other than `Sma` and `Ema` (unless its period
+    /// is 1).
+    pub fn new(
+        fastk_period: u32,
+        slowk_period: u32,
+        slowk_ma_type: MaType,
+        slowd_period: u32,
+        slowd_ma_type: MaType,
+    ) -> Result<StochStream, TaError> {
+        wrapper::stoch_lookback(
+            fastk_period,
+            slowk_period,
+            slowk_ma_type,
+            slowd_period,
+            slowd_ma_type,
+        )?;
+        Ok(StochStream {
+            _snapshot: Tag::new(),
+            fastk_period: fastk_period as usize,
+            highs: VecDeque::with_capacity(fastk_period as usize),
+            lows: VecDeque::with_capacity(fastk_period as usize),
+            slow_k: MaStream::new(slowk_period, slowk_ma_type)?,
+            slow_d: MaStream::new(slowd_period, slowd_ma_type)?,
+        })
+    }
+
+    /// Adds one bar, returns the slow %K and %D once both averages are warmed up.
+    pub fn next(&mut self, bar: Hlc) -> Option<StochValue> {
+        if self.highs.len() == self.fastk_period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(bar.high);
+        self.lows.push_back(bar.low);
+        if self.highs.len() < self.fastk_period {
+            return None;
+        }
+        let highest = self.highs.iter().copied().reduce(f64::max)?;
+        let lowest = self.lows.iter().copied().reduce(f64::min)?;
+        let diff = (highest - lowest) / 100.0;
+        let fast_k = if diff != 0.0 {
+            (bar.close - lowest) / diff
+        } else {
+            0.0
+        };
+        let slow_k = self.slow_k.next(fast_k)?;
+        let slow_d = self.slow_d.next(slow_k)?;
+        Some(StochValue { slow_k, slow_d })
+    }
+}
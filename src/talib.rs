@@ -0,0 +1,277 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::sync::{Condvar, Mutex, PoisonError, RwLock, RwLockReadGuard};
+use std::thread::{self, ThreadId};
+
+use crate::CandleSettingType;
+use crate::CandleSettings;
+use crate::Compatibility;
+use crate::TaError;
+use crate::UnstableFunc;
+
+struct State {
+    initialized: bool,
+    guards: usize,
+    settings: Settings,
+}
+
+/// Global settings of the C library changed through this crate.
+///
+/// TA_Initialize resets them, so they are kept here and pushed to the C library again every time
+/// it is initialized.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Settings {
+    pub(crate) unstable_periods: [u32; UnstableFunc::ALL.len()],
+    pub(crate) compatibility: Compatibility,
+    // `None` while the defaults of the C library are in use
+    pub(crate) candle: Option<CandleSettings>,
+}
+
+impl Settings {
+    const DEFAULT: Settings = Settings {
+        unstable_periods: [0; UnstableFunc::ALL.len()],
+        compatibility: Compatibility::Default,
+        candle: None,
+    };
+
+    fn apply(&self) -> Result<(), TaError> {
+        for (func, period) in UnstableFunc::ALL.iter().zip(self.unstable_periods) {
+            check(unsafe { crate::TA_SetUnstablePeriod(func.id(), period) })?;
+        }
+        check(unsafe { crate::TA_SetCompatibility(self.compatibility.into()) })?;
+        match &self.candle {
+            None => check(unsafe {
+                crate::TA_RestoreCandleDefaultSettings(
+                    crate::TA_CandleSettingType_TA_AllCandleSettings,
+                )
+            })?,
+            Some(candle) => {
+                for setting_type in CandleSettingType::ALL {
+                    let setting = candle.get(setting_type);
+                    check(unsafe {
+                        crate::TA_SetCandleSettings(
+                            setting_type.id(),
+                            setting.range_type.into(),
+                            setting.avg_period as crate::TA_Integer,
+                            setting.factor,
+                        )
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Wrappers hold the read side while the C library computes, so TA_Initialize and TA_Shutdown
+// (which rebuild the global state of the C library) only ever run while nothing else uses it.
+static STATE: RwLock<State> = RwLock::new(State {
+    initialized: false,
+    guards: 0,
+    settings: Settings::DEFAULT,
+});
+
+///
+/// Explicit handle on the process-wide TA-Lib state.
+///
+/// Wrappers initialize the library lazily on first use and keep it alive afterwards, so holding
+/// a `TaLib` is never required. It is there for callers who want to control when the C library
+/// is set up and torn down: the library stays initialized while at least one guard is alive, and
+/// dropping the last one calls `TA_Shutdown` once every running computation has finished. A later
+/// wrapper call initializes it again, with the global settings changed through this crate (such as
+/// [`crate::UnstablePeriod`], [`crate::Compatibility`] or [`crate::CandleSettings`]) applied again.
+///
+/// #Sample
+/// ```
+/// let talib = rust_ta_lib::TaLib::init().unwrap();
+/// let close_prices: Vec<f64> = vec![1.087010, 1.087120, 1.087080, 1.087170, 1.087110];
+/// let (sma_values, begin) = rust_ta_lib::wrapper::sma(3, &close_prices).unwrap();
+/// println!("{} values starting at {}", sma_values.len(), begin);
+/// drop(talib);
+/// ```
+#[derive(Debug)]
+pub struct TaLib {
+    _private: (),
+}
+
+impl TaLib {
+    /// Initializes the C library if needed and returns a guard keeping it alive.
+    pub fn init() -> Result<TaLib, TaError> {
+        let mut state = STATE.write().unwrap_or_else(PoisonError::into_inner);
+        initialize(&mut state)?;
+        state.guards += 1;
+        Ok(TaLib { _private: () })
+    }
+
+    /// Whether the C library is currently initialized.
+    pub fn is_initialized() -> bool {
+        STATE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .initialized
+    }
+}
+
+impl Drop for TaLib {
+    fn drop(&mut self) {
+        let mut state = STATE.write().unwrap_or_else(PoisonError::into_inner);
+        state.guards -= 1;
+        if state.guards == 0 && state.initialized {
+            unsafe { crate::TA_Shutdown() };
+            state.initialized = false;
+        }
+    }
+}
+
+thread_local! {
+    // Set while the thread holds the write side in `with_compatibility`, wrappers called from
+    // there must not take the read side
+    static EXCLUSIVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Shared access to the initialized C library, held by a wrapper for the length of one call.
+pub(crate) struct Handle {
+    _state: Option<RwLockReadGuard<'static, State>>,
+}
+
+/// Returns a handle on the C library, initializing it first if nobody has done it yet.
+pub(crate) fn handle() -> Result<Handle, TaError> {
+    if EXCLUSIVE.get() {
+        return Ok(Handle { _state: None });
+    }
+    loop {
+        let state = STATE.read().unwrap_or_else(PoisonError::into_inner);
+        if state.initialized {
+            return Ok(Handle {
+                _state: Some(state),
+            });
+        }
+        drop(state);
+        // Another thread may shut the library down between the write and the read lock,
+        // in that case just go around again
+        initialize(&mut STATE.write().unwrap_or_else(PoisonError::into_inner))?;
+    }
+}
+
+fn initialize(state: &mut State) -> Result<(), TaError> {
+    if !state.initialized {
+        check(unsafe { crate::TA_Initialize() })?;
+        // only a library running with every setting applied counts as initialized
+        if let Err(err) = state.settings.apply() {
+            unsafe { crate::TA_Shutdown() };
+            return Err(err);
+        }
+        state.initialized = true;
+    }
+    Ok(())
+}
+
+pub(crate) fn check(ret_code: crate::TA_RetCode) -> Result<(), TaError> {
+    match ret_code {
+        crate::TA_RetCode_TA_SUCCESS => Ok(()),
+        ret_code => Err(TaError::from_ret_code(ret_code)),
+    }
+}
+
+/// The global settings currently in use.
+pub(crate) fn settings() -> Settings {
+    STATE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .settings
+}
+
+/// Changes the global settings with `update`. No computation runs meanwhile, and the new settings
+/// reach the C library right away if it is initialized, or when it gets initialized otherwise.
+pub(crate) fn configure<R>(update: impl FnOnce(&mut Settings) -> R) -> Result<R, TaError> {
+    let mut state = STATE.write().unwrap_or_else(PoisonError::into_inner);
+    let previous = state.settings;
+    let result = update(&mut state.settings);
+    if state.initialized {
+        if let Err(err) = state.settings.apply() {
+            state.settings = previous;
+            state.settings.apply()?;
+            return Err(err);
+        }
+    }
+    Ok(result)
+}
+
+/// Runs `compute` with `compatibility` in place of the global mode. The mode is global to the C
+/// library, so no other computation runs meanwhile.
+pub(crate) fn with_compatibility<R>(
+    compatibility: Compatibility,
+    compute: impl FnOnce() -> Result<R, TaError>,
+) -> Result<R, TaError> {
+    let mut state = STATE.write().unwrap_or_else(PoisonError::into_inner);
+    initialize(&mut state)?;
+    check(unsafe { crate::TA_SetCompatibility(compatibility.into()) })?;
+    let _restore = RestoreCompatibility::new(state.settings.compatibility);
+    compute()
+}
+
+// Puts the global mode back once the computation is over, even if it panicked
+struct RestoreCompatibility(Compatibility);
+
+impl RestoreCompatibility {
+    fn new(global: Compatibility) -> Self {
+        EXCLUSIVE.set(true);
+        RestoreCompatibility(global)
+    }
+}
+
+impl Drop for RestoreCompatibility {
+    fn drop(&mut self) {
+        EXCLUSIVE.set(false);
+        // the global mode was accepted before, setting it again cannot fail
+        unsafe { crate::TA_SetCompatibility(self.0.into()) };
+    }
+}
+
+struct Scope {
+    owner: Option<ThreadId>,
+    depth: usize,
+}
+
+// Thread currently allowed to change the global settings, see `lock_scope`
+static SCOPE: Mutex<Scope> = Mutex::new(Scope {
+    owner: None,
+    depth: 0,
+});
+static SCOPE_RELEASED: Condvar = Condvar::new();
+
+/// Exclusive right for one thread to change the global settings, held by scoped overrides for
+/// their whole life so that two threads never restore each other's values.
+#[derive(Debug)]
+pub(crate) struct ScopeLock {
+    // released on the thread that took it
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Waits until no other thread holds a `ScopeLock`. The thread holding it can take it again, so
+/// overrides nest.
+pub(crate) fn lock_scope() -> ScopeLock {
+    let current = thread::current().id();
+    let mut scope = SCOPE.lock().unwrap_or_else(PoisonError::into_inner);
+    while scope.owner.is_some_and(|owner| owner != current) {
+        scope = SCOPE_RELEASED
+            .wait(scope)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+    scope.owner = Some(current);
+    scope.depth += 1;
+    ScopeLock {
+        _not_send: PhantomData,
+    }
+}
+
+impl Drop for ScopeLock {
+    fn drop(&mut self) {
+        let mut scope = SCOPE.lock().unwrap_or_else(PoisonError::into_inner);
+        scope.depth -= 1;
+        if scope.depth == 0 {
+            scope.owner = None;
+            SCOPE_RELEASED.notify_all();
+        }
+    }
+}
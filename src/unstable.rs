@@ -0,0 +1,183 @@
+use crate::talib::{self, ScopeLock};
+use crate::TaError;
+
+///
+/// Function whose output depends on an unstable period, the `TA_FUNC_UNST_*` ids of TA-Lib.
+///
+/// These functions carry a value from one bar to the next (EMA-like smoothing, Hilbert transform...),
+/// so their first outputs depend on where the input starts. The unstable period is the number of
+/// extra bars TA-Lib consumes before it starts writing outputs.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnstableFunc {
+    Adx,
+    Adxr,
+    Atr,
+    Cmo,
+    Dx,
+    Ema,
+    HtDcPeriod,
+    HtDcPhase,
+    HtPhasor,
+    HtSine,
+    HtTrendline,
+    HtTrendMode,
+    Imi,
+    Kama,
+    Mama,
+    Mfi,
+    MinusDi,
+    MinusDm,
+    Natr,
+    PlusDi,
+    PlusDm,
+    Rsi,
+    StochRsi,
+    T3,
+}
+
+impl UnstableFunc {
+    /// Every function with an unstable period, in the order of the C enum.
+    pub const ALL: [UnstableFunc; 24] = [
+        UnstableFunc::Adx,
+        UnstableFunc::Adxr,
+        UnstableFunc::Atr,
+        UnstableFunc::Cmo,
+        UnstableFunc::Dx,
+        UnstableFunc::Ema,
+        UnstableFunc::HtDcPeriod,
+        UnstableFunc::HtDcPhase,
+        UnstableFunc::HtPhasor,
+        UnstableFunc::HtSine,
+        UnstableFunc::HtTrendline,
+        UnstableFunc::HtTrendMode,
+        UnstableFunc::Imi,
+        UnstableFunc::Kama,
+        UnstableFunc::Mama,
+        UnstableFunc::Mfi,
+        UnstableFunc::MinusDi,
+        UnstableFunc::MinusDm,
+        UnstableFunc::Natr,
+        UnstableFunc::PlusDi,
+        UnstableFunc::PlusDm,
+        UnstableFunc::Rsi,
+        UnstableFunc::StochRsi,
+        UnstableFunc::T3,
+    ];
+
+    /// The `TA_FuncUnstId` of the function.
+    pub fn id(&self) -> crate::TA_FuncUnstId {
+        match self {
+            UnstableFunc::Adx => crate::TA_FuncUnstId_TA_FUNC_UNST_ADX,
+            UnstableFunc::Adxr => crate::TA_FuncUnstId_TA_FUNC_UNST_ADXR,
+            UnstableFunc::Atr => crate::TA_FuncUnstId_TA_FUNC_UNST_ATR,
+            UnstableFunc::Cmo => crate::TA_FuncUnstId_TA_FUNC_UNST_CMO,
+            UnstableFunc::Dx => crate::TA_FuncUnstId_TA_FUNC_UNST_DX,
+            UnstableFunc::Ema => crate::TA_FuncUnstId_TA_FUNC_UNST_EMA,
+            UnstableFunc::HtDcPeriod => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_DCPERIOD,
+            UnstableFunc::HtDcPhase => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_DCPHASE,
+            UnstableFunc::HtPhasor => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_PHASOR,
+            UnstableFunc::HtSine => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_SINE,
+            UnstableFunc::HtTrendline => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_TRENDLINE,
+            UnstableFunc::HtTrendMode => crate::TA_FuncUnstId_TA_FUNC_UNST_HT_TRENDMODE,
+            UnstableFunc::Imi => crate::TA_FuncUnstId_TA_FUNC_UNST_IMI,
+            UnstableFunc::Kama => crate::TA_FuncUnstId_TA_FUNC_UNST_KAMA,
+            UnstableFunc::Mama => crate::TA_FuncUnstId_TA_FUNC_UNST_MAMA,
+            UnstableFunc::Mfi => crate::TA_FuncUnstId_TA_FUNC_UNST_MFI,
+            UnstableFunc::MinusDi => crate::TA_FuncUnstId_TA_FUNC_UNST_MINUS_DI,
+            UnstableFunc::MinusDm => crate::TA_FuncUnstId_TA_FUNC_UNST_MINUS_DM,
+            UnstableFunc::Natr => crate::TA_FuncUnstId_TA_FUNC_UNST_NATR,
+            UnstableFunc::PlusDi => crate::TA_FuncUnstId_TA_FUNC_UNST_PLUS_DI,
+            UnstableFunc::PlusDm => crate::TA_FuncUnstId_TA_FUNC_UNST_PLUS_DM,
+            UnstableFunc::Rsi => crate::TA_FuncUnstId_TA_FUNC_UNST_RSI,
+            UnstableFunc::StochRsi => crate::TA_FuncUnstId_TA_FUNC_UNST_STOCHRSI,
+            UnstableFunc::T3 => crate::TA_FuncUnstId_TA_FUNC_UNST_T3,
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+///
+/// Unstable periods of the C library, and a guard overriding them for a scope.
+///
+/// The unstable periods are process-wide: they apply to every computation on every thread. They
+/// are kept across `TA_Shutdown`/`TA_Initialize`, see [`crate::TaLib`].
+///
+/// A guard returned by [`UnstablePeriod::scoped`] or [`UnstablePeriod::scoped_all`] restores the
+/// previous values when dropped. Only one thread at a time can hold guards, other threads block
+/// in `scoped` (and in `set`) until the last guard of the owning thread is dropped. Guards can be
+/// nested on the owning thread, and must be dropped in reverse order.
+///
+/// #Sample
+/// ```
+/// use rust_ta_lib::{UnstableFunc, UnstablePeriod};
+///
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// {
+///     let _guard = UnstablePeriod::scoped(UnstableFunc::Rsi, 20).unwrap();
+///     assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 20);
+///     let (rsi, begin) = rust_ta_lib::wrapper::rsi(14, &close_prices).unwrap();
+///     println!("{} values starting at {}", rsi.len(), begin);
+/// }
+/// assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 0);
+/// ```
+#[must_use = "the previous unstable periods are restored when the guard is dropped"]
+#[derive(Debug)]
+pub struct UnstablePeriod {
+    previous: [u32; UnstableFunc::ALL.len()],
+    _scope: ScopeLock,
+}
+
+impl UnstablePeriod {
+    /// The unstable period currently used for `func`.
+    pub fn get(func: UnstableFunc) -> u32 {
+        talib::settings().unstable_periods[func.index()]
+    }
+
+    /// Sets the unstable period of `func`, the `TA_SetUnstablePeriod` of TA-Lib.
+    pub fn set(func: UnstableFunc, period: u32) -> Result<(), TaError> {
+        let _scope = talib::lock_scope();
+        talib::configure(|settings| settings.unstable_periods[func.index()] = period)
+    }
+
+    /// Sets the unstable period of every function, `TA_SetUnstablePeriod` with `TA_FUNC_UNST_ALL`.
+    pub fn set_all(period: u32) -> Result<(), TaError> {
+        let _scope = talib::lock_scope();
+        talib::configure(|settings| settings.unstable_periods = [period; UnstableFunc::ALL.len()])
+    }
+
+    /// Sets the unstable period of `func` until the returned guard is dropped.
+    pub fn scoped(func: UnstableFunc, period: u32) -> Result<UnstablePeriod, TaError> {
+        UnstablePeriod::override_with(|periods| periods[func.index()] = period)
+    }
+
+    /// Sets the unstable period of every function until the returned guard is dropped.
+    pub fn scoped_all(period: u32) -> Result<UnstablePeriod, TaError> {
+        UnstablePeriod::override_with(|periods| *periods = [period; UnstableFunc::ALL.len()])
+    }
+
+    fn override_with(
+        update: impl FnOnce(&mut [u32; UnstableFunc::ALL.len()]),
+    ) -> Result<UnstablePeriod, TaError> {
+        let scope = talib::lock_scope();
+        let previous = talib::configure(|settings| {
+            let previous = settings.unstable_periods;
+            update(&mut settings.unstable_periods);
+            previous
+        })?;
+        Ok(UnstablePeriod {
+            previous,
+            _scope: scope,
+        })
+    }
+}
+
+impl Drop for UnstablePeriod {
+    fn drop(&mut self) {
+        // putting back values that were accepted before cannot fail
+        let _ = talib::configure(|settings| settings.unstable_periods = self.previous);
+    }
+}
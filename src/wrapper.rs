@@ -1,123 +1,406 @@
-/// TA_ACCBANDS - Acceleration Bands
-/// #Sample
-/// ```
-/// let close_prices: Vec<f64> = vec![
-///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
-///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
-///        1.086670, 1.086630,
-/// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (uppers,middles,lowers, begin) = rust_ta_lib::wrapper::accbands(10, &high_prices,&low_prices,&close_prices);
-/// for (index, value) in uppers.iter().enumerate() {
-///        println!("upper index {} = {}", begin + index as i32 + 1, value);
-///        println!("middle index {} = {:?}", begin + index as i32 + 1, middles.get(index));
-///        println!("lower index {} = {:?}", begin + index as i32 + 1,  lowers.get(index));
-///  }
-/// ```
-pub fn accbands(
-    period: u32,
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut outUpper: Vec<f64> = Vec::with_capacity(close.len());
-    let mut middleUpper: Vec<f64> = Vec::with_capacity(close.len());
-    let mut lowerUpper: Vec<f64> = Vec::with_capacity(close.len());
+//!
+//! Safe wrappers over the TA-Lib functions.
+//!
+//! Every indicator is generic over its input type through [`TaFloat`]: `f64` series are passed to
+//! `TA_*` and `f32` series to the single precision `TA_S_*` variant of the same function.
+//!
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::CandleSignal;
+use crate::Compatibility;
+use crate::MaType;
+use crate::TaError;
+use crate::TaFloat;
+
+// Returns the common length of the input series, or an error naming the first one that differs
+pub(crate) fn input_len(lens: &[usize]) -> Result<usize, TaError> {
+    let expected = lens[0];
+    match lens.iter().find(|len| **len != expected) {
+        Some(&found) => Err(TaError::InputLengthMismatch { expected, found }),
+        None => Ok(expected),
+    }
+}
+
+// Checks an option against the range TA-Lib documents for it before it reaches the C library.
+// Integer options are shadowed by their value converted for the C API.
+macro_rules! param {
+    ($name:ident: int $min:literal..=$max:literal) => {
+        let $name = int_param(
+            stringify!($name),
+            $name,
+            $min..=$max,
+            concat!("from ", $min, " to ", $max),
+        )?;
+    };
+    ($name:ident: real $min:literal..=$max:literal) => {
+        real_param(
+            stringify!($name),
+            $name,
+            $min..=$max,
+            concat!("from ", $min, " to ", $max),
+        )?;
+    };
+}
+
+fn int_param<V: TryInto<crate::TA_Integer>>(
+    name: &'static str,
+    value: V,
+    valid: RangeInclusive<crate::TA_Integer>,
+    range: &'static str,
+) -> Result<crate::TA_Integer, TaError> {
+    match value.try_into() {
+        Ok(value) if valid.contains(&value) => Ok(value),
+        _ => Err(TaError::ParamOutOfRange { name, range }),
+    }
+}
+
+fn real_param(
+    name: &'static str,
+    value: f64,
+    valid: RangeInclusive<f64>,
+    range: &'static str,
+) -> Result<(), TaError> {
+    // NaN is never contained in a range
+    if valid.contains(&value) {
+        Ok(())
+    } else {
+        Err(TaError::ParamOutOfRange { name, range })
+    }
+}
+
+// Resolves `range` against an input of `len` values into the startIdx/endIdx pair of the C API,
+// None when the range is empty
+fn index_range(
+    len: usize,
+    range: impl RangeBounds<usize>,
+) -> Result<Option<(crate::TA_Integer, crate::TA_Integer)>, TaError> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(TaError::OutOfRangeStartIndex)?,
+        Bound::Unbounded => 0,
+    };
+    // one past the last index
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(TaError::OutOfRangeEndIndex)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if end > len || end > crate::TA_Integer::MAX as usize {
+        return Err(TaError::OutOfRangeEndIndex);
+    }
+    if start > end {
+        return Err(TaError::OutOfRangeStartIndex);
+    }
+    if start == end {
+        return Ok(None);
+    }
+    Ok(Some((
+        start as crate::TA_Integer,
+        end as crate::TA_Integer - 1,
+    )))
+}
+
+// Shared body of the wrappers: `call` runs the TA-Lib function over the inputs in `range`,
+// writing into N buffers big enough for the whole range, then every buffer is sized from the
+// number of values TA-Lib reported
+fn compute<O, const N: usize, F>(
+    len: usize,
+    range: impl RangeBounds<usize>,
+    call: F,
+) -> Result<([Vec<O>; N], crate::TA_Integer), TaError>
+where
+    F: FnOnce(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        &mut crate::TA_Integer,
+        &mut crate::TA_Integer,
+        [*mut O; N],
+    ) -> crate::TA_RetCode,
+{
+    let (start, end) = match index_range(len, range)? {
+        Some(bounds) => bounds,
+        // nothing to compute, TA-Lib would reject the range
+        None => return Ok((std::array::from_fn(|_| Vec::new()), 0)),
+    };
+    let capacity = (end - start) as usize + 1;
+    let mut outs: [Vec<O>; N] = std::array::from_fn(|_| Vec::with_capacity(capacity));
     let mut out_begin: crate::TA_Integer = 0;
     let mut out_size: crate::TA_Integer = 0;
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_ACCBANDS(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            outUpper.as_mut_ptr(),  // pointer to the first element of the output vector
-            middleUpper.as_mut_ptr(),
-            lowerUpper.as_mut_ptr(),
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => outUpper.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
+    let _lib = crate::talib::handle()?;
+    let ret_code = call(
+        start,          // the first index of the input vector to use
+        end,            // the last index of the input vector to use
+        &mut out_begin, // set to index of the first input to have an output value
+        &mut out_size,  // set to number of values computed
+        std::array::from_fn(|i| outs[i].as_mut_ptr()),
+    );
+    match ret_code {
+        // TA-lib wrote out_size values (never more than end - start + 1) in each buffer
+        crate::TA_RetCode_TA_SUCCESS => {
+            for out in outs.iter_mut() {
+                unsafe { out.set_len(out_size as usize) };
+            }
         }
-        crate::TA_Shutdown();
+        // An error occured
+        _ => return Err(TaError::from_ret_code(ret_code)),
+    }
+
+    Ok((outs, out_begin))
+}
+
+// Shared body of the `_into` wrappers: checks every caller buffer can hold the values TA-Lib
+// will write for the whole input, then runs `call` over it writing straight into the buffers
+fn compute_into<O, const N: usize, L, F>(
+    len: usize,
+    lookback: L,
+    outs: [&mut [O]; N],
+    call: F,
+) -> Result<(crate::TA_Integer, usize), TaError>
+where
+    L: FnOnce() -> crate::TA_Integer,
+    F: FnOnce(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        &mut crate::TA_Integer,
+        &mut crate::TA_Integer,
+        [*mut O; N],
+    ) -> crate::TA_RetCode,
+{
+    let (start, end) = match index_range(len, ..)? {
+        Some(bounds) => bounds,
+        None => return Ok((0, 0)),
+    };
+    // hold the library for the lookback too, so that both see the same global settings
+    let _lib = crate::talib::handle()?;
+    let lookback = lookback();
+    if lookback < 0 {
+        return Err(TaError::BadParam);
+    }
+    let required = len.saturating_sub(lookback as usize);
+    if let Some(out) = outs.iter().find(|out| out.len() < required) {
+        return Err(TaError::OutputTooSmall {
+            required,
+            found: out.len(),
+        });
+    }
+
+    let mut out_begin: crate::TA_Integer = 0;
+    let mut out_size: crate::TA_Integer = 0;
+    let ret_code = call(
+        start,          // the first index of the input vector to use
+        end,            // the last index of the input vector to use
+        &mut out_begin, // set to index of the first input to have an output value
+        &mut out_size,  // set to number of values computed
+        outs.map(|out| out.as_mut_ptr()),
+    );
+    match ret_code {
+        crate::TA_RetCode_TA_SUCCESS => Ok((out_begin, out_size as usize)),
+        // An error occured
+        _ => Err(TaError::from_ret_code(ret_code)),
+    }
+}
+
+// Converts the value returned by a TA_<FUNC>_Lookback function, -1 means invalid parameters
+fn lookback<F>(call: F) -> Result<usize, TaError>
+where
+    F: FnOnce() -> crate::TA_Integer,
+{
+    // the lookback of some functions depends on global settings (unstable periods, candle
+    // settings) which only exist once the library is initialized
+    let _lib = crate::talib::handle()?;
+    match call() {
+        lookback if lookback >= 0 => Ok(lookback as usize),
+        _ => Err(TaError::BadParam),
     }
+}
+
+// Pads `values`, whose first element belongs to input `begin`, with `fill` up to `len` values so
+// that value i lines up with input i
+fn align<O: Clone>(values: Vec<O>, begin: crate::TA_Integer, len: usize, fill: O) -> Vec<O> {
+    let mut aligned = Vec::with_capacity(len);
+    aligned.resize(begin as usize, fill.clone());
+    aligned.extend(values);
+    aligned.resize(len, fill);
+    aligned
+}
+
+// `align` for outputs without a NaN, warm-up values are None
+fn align_some<O: Clone>(values: Vec<O>, begin: crate::TA_Integer, len: usize) -> Vec<Option<O>> {
+    align(values.into_iter().map(Some).collect(), begin, len, None)
+}
+
+// Shared body of the candlestick pattern functions, the raw TA_Integer output is converted to
+// signals
+fn candle<F>(
+    len: usize,
+    range: impl RangeBounds<usize>,
+    call: F,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError>
+where
+    F: FnOnce(
+        crate::TA_Integer,
+        crate::TA_Integer,
+        &mut crate::TA_Integer,
+        &mut crate::TA_Integer,
+        *mut crate::TA_Integer,
+    ) -> crate::TA_RetCode,
+{
+    let ([out], out_begin) = compute(len, range, |start, end, out_begin, out_size, [out]| {
+        call(start, end, out_begin, out_size, out)
+    })?;
+
+    Ok((
+        out.into_iter().map(CandleSignal::from_raw).collect(),
+        out_begin,
+    ))
+}
 
-    (outUpper, middleUpper, lowerUpper, out_begin)
+/// Output of [`accbands`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccBands {
+    /// Upper band
+    pub upper: Vec<f64>,
+    /// Middle band, the simple moving average
+    pub middle: Vec<f64>,
+    /// Lower band
+    pub lower: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
 }
 
 /// TA_ACCBANDS - Acceleration Bands
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
 /// let high_prices = close_prices.clone();
 /// let low_prices = close_prices.clone();
-/// let (uppers,middles,lowers, begin) = rust_ta_lib::wrapper::s_accbands(10, &high_prices,&low_prices,&close_prices);
+/// let rust_ta_lib::wrapper::AccBands { upper: uppers, middle: middles, lower: lowers, begin } = rust_ta_lib::wrapper::accbands(10, &high_prices,&low_prices,&close_prices).unwrap();
 /// for (index, value) in uppers.iter().enumerate() {
 ///        println!("upper index {} = {}", begin + index as i32 + 1, value);
 ///        println!("middle index {} = {:?}", begin + index as i32 + 1, middles.get(index));
 ///        println!("lower index {} = {:?}", begin + index as i32 + 1,  lowers.get(index));
 ///  }
 /// ```
-pub fn s_accbands(
+pub fn accbands<T: TaFloat>(
     period: u32,
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut outUpper: Vec<f64> = Vec::with_capacity(close.len());
-    let mut middleUpper: Vec<f64> = Vec::with_capacity(close.len());
-    let mut lowerUpper: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<AccBands, TaError> {
+    accbands_range(period, high, low, close, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_ACCBANDS(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            outUpper.as_mut_ptr(),  // pointer to the first element of the output vector
-            middleUpper.as_mut_ptr(),
-            lowerUpper.as_mut_ptr(),
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => outUpper.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`accbands`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn accbands_range<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<AccBands, TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 2..=100000);
+    let ([outUpper, middleUpper, lowerUpper], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [outUpper, middleUpper, lowerUpper]| unsafe {
+            (T::FFI.accbands)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                outUpper,
+                middleUpper,
+                lowerUpper,
+            )
+        },
+    )?;
 
-    (outUpper, middleUpper, lowerUpper, out_begin)
+    Ok(AccBands {
+        upper: outUpper,
+        middle: middleUpper,
+        lower: lowerUpper,
+        begin: out_begin,
+    })
 }
 
+/// [`accbands`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_COS - Vector Trigonometric Cos
+/// Each buffer needs room for the input length minus [`accbands_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn accbands_into<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    upper: &mut [f64],
+    middle: &mut [f64],
+    lower: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 2..=100000);
+    compute_into(
+        len,
+        || unsafe { crate::TA_ACCBANDS_Lookback(period) },
+        [upper, middle, lower],
+        |start, end, out_begin, out_size, [outUpper, middleUpper, lowerUpper]| unsafe {
+            (T::FFI.accbands)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                outUpper,
+                middleUpper,
+                lowerUpper,
+            )
+        },
+    )
+}
+
+/// [`accbands`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn accbands_aligned<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<AccBands, TaError> {
+    let out = accbands(period, high, low, close)?;
+    let len = high.len();
+    Ok(AccBands {
+        upper: align(out.upper, out.begin, len, f64::NAN),
+        middle: align(out.middle, out.begin, len, f64::NAN),
+        lower: align(out.lower, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`accbands`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn accbands_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    lookback(|| unsafe { crate::TA_ACCBANDS_Lookback(period) })
+}
+
+///
+/// TA_ACOS - Vector Trigonometric ACos
 ///
 /// Input  = double
 /// Output = double
@@ -128,89 +411,80 @@ pub fn s_accbands(
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::acos(&close_prices);
+/// let (outs, begin) = rust_ta_lib::wrapper::acos(&close_prices).unwrap();
 /// for (index, value) in outs.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn acos(close: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn acos<T: TaFloat>(close: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    acos_range(close, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_COS(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`acos`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn acos_range<T: TaFloat>(
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let ([out], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.acos)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
 
+/// [`acos`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_COS - Vector Trigonometric Cos
-///
-/// Input  = double
-/// Output = double
-/// #Sample
-/// ```
-/// let close_prices: Vec<f32> = vec![
-///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
-///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
-///        1.086670, 1.086630,
-/// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::s_acos(&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
-///  }
-/// ```
-pub fn s_acos(close: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// Each buffer needs room for the input length minus [`acos_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn acos_into<T: TaFloat>(
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_ACOS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.acos)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_S_COS(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`acos`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn acos_aligned<T: TaFloat>(close: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = acos(close)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`acos`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn acos_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_ACOS_Lookback() })
 }
+
 ///
 /// TA_AD - Chaikin A/D Line
 ///
@@ -226,107 +500,200 @@ pub fn s_acos(close: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
 /// let high_prices = close_prices.clone();
 /// let low_prices = close_prices.clone();
 /// let volume = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::ad( &high_prices,&low_prices,&close_prices,&volume);
+/// let (outs, begin) = rust_ta_lib::wrapper::ad( &high_prices,&low_prices,&close_prices,&volume).unwrap();
 /// for (index, value) in outs.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn ad(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-    volume: &Vec<f64>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn ad<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ad_range(high, low, close, volume, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_AD(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            volume.as_ptr(),        // pointer to the first element of the volume vector
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`ad`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ad_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ad)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the first element of the high vector
+                low.as_ptr(),    // pointer to the first element of the low vector
+                close.as_ptr(),  // pointer to the first element of the close vector
+                volume.as_ptr(), // pointer to the first element of the volume vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`ad`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_AD - Chaikin A/D Line
+/// Each buffer needs room for the input length minus [`ad_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ad_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    compute_into(
+        len,
+        || unsafe { crate::TA_AD_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ad)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the first element of the high vector
+                low.as_ptr(),    // pointer to the first element of the low vector
+                close.as_ptr(),  // pointer to the first element of the close vector
+                volume.as_ptr(), // pointer to the first element of the volume vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ad`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ad_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ad(high, low, close, volume)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ad`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ad_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_AD_Lookback() })
+}
+
+/// TA_ADD - Vector Arithmetic Add
 ///
-/// Input  = High, Low, Close, Volume
+/// Input  = double, double
 /// Output = double
+///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let volume = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::s_ad( &high_prices,&low_prices,&close_prices,&volume);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::add(&close_prices, &in_real1).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_ad(
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-    volume: &Vec<f32>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn add<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    add_range(in_real0, in_real1, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_AD(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            volume.as_ptr(),        // pointer to the first element of the volume vector
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`add`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn add_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.add)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`add`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`add_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn add_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ADD_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.add)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`add`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn add_aligned<T: TaFloat>(in_real0: &[T], in_real1: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = add(in_real0, in_real1)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`add`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn add_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_ADD_Lookback() })
 }
-pub fn add() {}
-pub fn s_add() {}
 
 ///
 /// TA_ADOSC - Aroon Oscillator
@@ -339,53 +706,118 @@ pub fn s_add() {}
 ///
 ///    2nd: the first index of inputs corresponding to an valid output value
 ///
-pub fn adosc(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-    volume: &Vec<f64>,
+pub fn adosc<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
     fastperiod: i32,
     slowperiod: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let hlen = high.len();
-    if hlen.ne(&low.len()) || hlen.ne(&close.len()) || hlen.ne(&volume.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    adosc_range(high, low, close, volume, fastperiod, slowperiod, ..)
+}
 
-    let mut out: Vec<f64> = Vec::with_capacity(hlen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// [`adosc`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn adosc_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_ADOSC(
-            0,                // the first index of the input vector to use
-            hlen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            volume.as_ptr(),  // pointer to the volume vector
-            fastperiod,       // fast period
-            slowperiod,       // slow period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adosc)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the high vector
+                low.as_ptr(),    // pointer to the low vector
+                close.as_ptr(),  // pointer to the close vector
+                volume.as_ptr(), // pointer to the volume vector
+                fastperiod,      // fast period
+                slowperiod,      // slow period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`adosc`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`adosc_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn adosc_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
 
-    (out, out_begin)
+    compute_into(
+        len,
+        || unsafe { crate::TA_ADOSC_Lookback(fastperiod, slowperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adosc)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the high vector
+                low.as_ptr(),    // pointer to the low vector
+                close.as_ptr(),  // pointer to the close vector
+                volume.as_ptr(), // pointer to the volume vector
+                fastperiod,      // fast period
+                slowperiod,      // slow period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`adosc`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn adosc_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = adosc(high, low, close, volume, fastperiod, slowperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`adosc`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn adosc_lookback(fastperiod: i32, slowperiod: i32) -> Result<usize, TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_ADOSC_Lookback(fastperiod, slowperiod) })
 }
-pub fn s_adosc() {}
 
 ///
 /// TA_ADX - Average Directional Movement Index
@@ -401,104 +833,227 @@ pub fn s_adosc() {}
 /// ];
 /// let high_prices = close_prices.clone();
 /// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::adx( 10,&high_prices,&low_prices,&close_prices);
+/// let (outs, begin) = rust_ta_lib::wrapper::adx( 10,&high_prices,&low_prices,&close_prices).unwrap();
 /// for (index, value) in outs.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn adx(
+pub fn adx<T: TaFloat>(
     period: u32,
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    adx_range(period, high, low, close, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_ADX(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`adx`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn adx_range<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adx)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`adx`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_ADX - Average Directional Movement Index
+/// Each buffer needs room for the input length minus [`adx_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn adx_into<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 2..=100000);
+    compute_into(
+        len,
+        || unsafe { crate::TA_ADX_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adx)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`adx`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn adx_aligned<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = adx(period, high, low, close)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`adx`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn adx_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    lookback(|| unsafe { crate::TA_ADX_Lookback(period) })
+}
+
+/// TA_ADXR - Average Directional Movement Index Rating
 ///
-/// Input  = High, Low, Close, Volume
+/// Input  = double, double, double
 /// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::s_adx( 10,&high_prices,&low_prices,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::adxr(&high_prices, &low_prices, &close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_adx(
-    period: u32,
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn adxr<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    adxr_range(high, low, close, timeperiod, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_ADX(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`adxr`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn adxr_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adxr)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`adxr`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`adxr_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn adxr_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ADXR_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.adxr)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
 
-    (out, out_begin)
+/// [`adxr`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn adxr_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = adxr(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`adxr`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn adxr_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_ADXR_Lookback(timeperiod) })
 }
-pub fn adxr() {}
-pub fn s_adxr() {}
 
 ///
 /// TA_APO - Absolute Price Oscillator
@@ -511,194 +1066,137 @@ pub fn s_adxr() {}
 ///
 ///    2nd: the first index of inputs corresponding to an valid output value
 ///
-pub fn apo(
-    close: &Vec<f64>,
+pub fn apo<T: TaFloat>(
+    close: &[T],
     fastperiod: i32,
     slowperiod: i32,
-    matype: crate::TA_MAType,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_APO(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fastperiod,             // fast period (suggest default 12)
-            slowperiod,             // slow period (suggest default 26)
-            matype,                 // MA Type
-            &mut out_begin,         // set to index of the first close to have an valid output value
-            &mut out_size,          // set to number of values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
-
-    (out, out_begin)
+    matype: MaType,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    apo_range(close, fastperiod, slowperiod, matype, ..)
 }
-pub fn s_apo() {}
-pub fn aroon() {}
-pub fn s_aroon() {}
 
+/// [`apo`] restricted to the inputs whose index is in `range`
 ///
-/// TA_AROONOSC - Aroon Oscillator
-///
-/// `Input`:  high, low, timeperiod
-///
-/// `Output`: (1st, 2nd)
-///
-///    1st: output vector(f64)
-///
-///    2nd: the first index of inputs corresponding to an valid output value
-///
-pub fn aroonosc(high: &Vec<f64>, low: &Vec<f64>, timeperiod: i32) -> (Vec<f64>, crate::TA_Integer) {
-    let hlen = high.len();
-    if hlen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn apo_range<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.apo)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastperiod,     // fast period (suggest default 12)
+                slowperiod,     // slow period (suggest default 26)
+                matype.into(),  // MA Type
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    let mut out: Vec<f64> = Vec::with_capacity(hlen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    Ok((out, out_begin))
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_AROONOSC(
-            0,                // the first index of the input vector to use
-            hlen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            timeperiod,       // time period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`apo`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`apo_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn apo_into<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_APO_Lookback(fastperiod, slowperiod, matype.into()) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.apo)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastperiod,     // fast period (suggest default 12)
+                slowperiod,     // slow period (suggest default 26)
+                matype.into(),  // MA Type
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
 
-    (out, out_begin)
+/// [`apo`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn apo_aligned<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = apo(close, fastperiod, slowperiod, matype)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
 }
-pub fn s_aroonosc() {}
-pub fn asin() {}
-pub fn s_asin() {}
-pub fn atan() {}
-pub fn s_atan() {}
-pub fn atr(
-    period: u32,
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_ATR(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`apo`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn apo_compat<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || apo(close, fastperiod, slowperiod, matype))
+}
 
-    (out, out_begin)
+/// Number of input values [`apo`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn apo_lookback(fastperiod: i32, slowperiod: i32, matype: MaType) -> Result<usize, TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_APO_Lookback(fastperiod, slowperiod, matype.into()) })
 }
-pub fn s_atr(
-    period: u32,
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
-    let mut out: Vec<crate::TA_Real> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_ATR(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the atr
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
 
-    (out, out_begin)
+/// Output of [`aroon`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aroon {
+    /// Aroon down
+    pub down: Vec<f64>,
+    /// Aroon up
+    pub up: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
 }
-pub fn avgprice() {}
-pub fn s_avgprice() {}
-pub fn avgdev() {}
-pub fn s_avgdev() {}
-///
-/// TA_BBANDS - Bollinger Bands
+
+/// TA_AROON - Aroon
 ///
-/// Input  = double
-/// Output = double, double, double
+/// Input  = double, double
+/// Output = double, double
 ///
 /// Optional Parameters
 /// -------------------
 /// optInTimePeriod:(From 2 to 100000)
 ///    Number of period
 ///
-/// optInNbDevUp:(From TA_REAL_MIN to TA_REAL_MAX)
-///    Deviation multiplier for upper band
-///
-/// optInNbDevDn:(From TA_REAL_MIN to TA_REAL_MAX)
-///    Deviation multiplier for lower band
-///
-/// optInMAType:
-///    Type of Moving Average
-///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -706,142 +1204,111 @@ pub fn s_avgdev() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs,_,_, begin) = rust_ta_lib::wrapper::bbands( 10,&close_prices,0.2,0.3,rust_ta_lib::TA_MAType_TA_MAType_SMA);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let rust_ta_lib::wrapper::Aroon { down: aroondown, up: aroonup, begin } = rust_ta_lib::wrapper::aroon(&high_prices, &low_prices, 14).unwrap();
+/// for (index, value) in aroondown.iter().enumerate() {
+///        println!("aroondown index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn bbands(
-    period: u32,
-    in_real: &Vec<f64>,
-    in_db_dev_up: f64,
-    in_db_dev_down: f64,
-    in_ma_type: crate::TA_MAType,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut upper_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut middle_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut lower_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_BBANDS(
-            0,                        // index of the first close to use
-            in_real.len() as i32 - 1, // index of the last close to use
-            in_real.as_ptr(),         // pointer to the first element of the high vector
-            period as i32,            // pointer to the first element of the low vector
-            in_db_dev_up,             // pointer to the first element of the close vector
-            in_db_dev_down,           // period of the atr
-            in_ma_type,
-            &mut out_begin, // set to index of the first close to have an atr value
-            &mut out_size,  // set to number of atr values computed
-            upper_band.as_mut_ptr(), // pointer to the first element of the output vector
-            middle_band.as_mut_ptr(), // pointer to the first element of the output vector
-            lower_band.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                upper_band.set_len(out_size as usize);
-                middle_band.set_len(out_size as usize);
-                lower_band.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
-
-    (upper_band, middle_band, lower_band, out_begin)
+pub fn aroon<T: TaFloat>(high: &[T], low: &[T], timeperiod: u32) -> Result<Aroon, TaError> {
+    aroon_range(high, low, timeperiod, ..)
 }
+
+/// [`aroon`] restricted to the inputs whose index is in `range`
 ///
-/// TA_S_BBANDS - Bollinger Bands
-///
-/// Input  = double
-/// Output = double, double, double
-///
-/// Optional Parameters
-/// -------------------
-/// optInTimePeriod:(From 2 to 100000)
-///    Number of period
-///
-/// optInNbDevUp:(From TA_REAL_MIN to TA_REAL_MAX)
-///    Deviation multiplier for upper band
-///
-/// optInNbDevDn:(From TA_REAL_MIN to TA_REAL_MAX)
-///    Deviation multiplier for lower band
-///
-/// optInMAType:
-///    Type of Moving Average
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn aroon_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<Aroon, TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
+    let ([aroondown, aroonup], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [aroondown, aroonup]| unsafe {
+            (T::FFI.aroon)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                aroondown,
+                aroonup,
+            )
+        },
+    )?;
+
+    Ok(Aroon {
+        down: aroondown,
+        up: aroonup,
+        begin: out_begin,
+    })
+}
+
+/// [`aroon`] writing into caller-provided buffers instead of allocating
 ///
-/// #Sample
-/// ```
-/// let close_prices: Vec<f32> = vec![
-///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
-///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
-///        1.086670, 1.086630,
-/// ];
-/// let (outs,_,_, begin) = rust_ta_lib::wrapper::s_bbands( 10,&close_prices,0.2,0.3,rust_ta_lib::TA_MAType_TA_MAType_SMA);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
-///  }
-/// ```
-pub fn s_bbands(
-    period: u32,
-    in_real: &Vec<f32>,
-    in_db_dev_up: f64,
-    in_db_dev_down: f64,
-    in_ma_type: crate::TA_MAType,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut upper_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut middle_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut lower_band: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_BBANDS(
-            0,                        // index of the first close to use
-            in_real.len() as i32 - 1, // index of the last close to use
-            in_real.as_ptr(),         // pointer to the first element of the high vector
-            period as i32,            // pointer to the first element of the low vector
-            in_db_dev_up,             // pointer to the first element of the close vector
-            in_db_dev_down,           // period of the atr
-            in_ma_type,
-            &mut out_begin, // set to index of the first close to have an atr value
-            &mut out_size,  // set to number of atr values computed
-            upper_band.as_mut_ptr(), // pointer to the first element of the output vector
-            middle_band.as_mut_ptr(), // pointer to the first element of the output vector
-            lower_band.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                upper_band.set_len(out_size as usize);
-                middle_band.set_len(out_size as usize);
-                lower_band.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// Each buffer needs room for the input length minus [`aroon_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn aroon_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+    aroondown: &mut [f64],
+    aroonup: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_AROON_Lookback(timeperiod) },
+        [aroondown, aroonup],
+        |start, end, out_begin, out_size, [aroondown, aroonup]| unsafe {
+            (T::FFI.aroon)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                aroondown,
+                aroonup,
+            )
+        },
+    )
+}
 
-    (upper_band, middle_band, lower_band, out_begin)
+/// [`aroon`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn aroon_aligned<T: TaFloat>(high: &[T], low: &[T], timeperiod: u32) -> Result<Aroon, TaError> {
+    let out = aroon(high, low, timeperiod)?;
+    let len = high.len();
+    Ok(Aroon {
+        down: align(out.down, out.begin, len, f64::NAN),
+        up: align(out.up, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`aroon`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn aroon_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_AROON_Lookback(timeperiod) })
 }
-pub fn beta() {}
-pub fn s_beta() {}
-pub fn bop() {}
-pub fn s_bop() {}
 
 ///
-/// TA_CCI - Commodity Channel Index
+/// TA_AROONOSC - Aroon Oscillator
 ///
-/// `Input`:  high, low, close, timeperiod
+/// `Input`:  high, low, timeperiod
 ///
 /// `Output`: (1st, 2nd)
 ///
@@ -849,226 +1316,103 @@ pub fn s_bop() {}
 ///
 ///    2nd: the first index of inputs corresponding to an valid output value
 ///
-pub fn cci(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
+pub fn aroonosc<T: TaFloat>(
+    high: &[T],
+    low: &[T],
     timeperiod: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    aroonosc_range(high, low, timeperiod, ..)
+}
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// [`aroonosc`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn aroonosc_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_CCI(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            timeperiod,       // time period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.aroonosc)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the high vector
+                low.as_ptr(),  // pointer to the low vector
+                timeperiod,    // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
 
-    (out, out_begin)
-}
-pub fn s_cci() {}
-pub fn cdl2crows() {}
-pub fn s_cdl2crows() {}
-pub fn cdl3blackcrows() {}
-pub fn s_cdl3blackcrows() {}
-pub fn cdl3inside() {}
-pub fn s_cdl3inside() {}
-pub fn cdl3linestrike() {}
-pub fn s_cdl3linestrike() {}
-pub fn cdl3outside() {}
-pub fn s_cdl3outside() {}
-pub fn cdl3starsinsouth() {}
-pub fn s_cdl3starsinsouth() {}
-pub fn cdl3whitesoldiers() {}
-pub fn s_cdl3whitesoldiers() {}
-pub fn cdlabandonedbaby() {}
-pub fn s_cdlabandonedbaby() {}
-pub fn cdladvanceblock() {}
-pub fn s_cdladvanceblock() {}
-pub fn cdlbelthold() {}
-pub fn s_cdlbelthold() {}
-pub fn cdlbreakaway() {}
-pub fn s_cdlbreakaway() {}
-pub fn cdlclosingmarubozu() {}
-pub fn s_cdlclosingmarubozu() {}
-pub fn cdlconcealbabyswall() {}
-pub fn s_cdlconcealbabyswall() {}
-pub fn cdlcounterattack() {}
-pub fn s_cdlcounterattack() {}
-pub fn cdldarkcloudcover() {}
-pub fn s_cdldarkcloudcover() {}
-pub fn cdldoji() {}
-pub fn s_cdldoji() {}
-pub fn cdldojistar() {}
-pub fn s_cdldojistar() {}
-pub fn cdldragonflydoji() {}
-pub fn s_cdldragonflydoji() {}
-pub fn cdlengulfing() {}
-pub fn s_cdlengulfing() {}
-pub fn cdleveningdojistar() {}
-pub fn s_cdleveningdojistar() {}
-pub fn cdleveningstar() {}
-pub fn s_cdleveningstar() {}
-pub fn cdlgapsidesidewhite() {}
-pub fn s_cdlgapsidesidewhite() {}
-pub fn cdlgravestonedoji() {}
-pub fn s_cdlgravestonedoji() {}
-pub fn cdlhammer() {}
-pub fn s_cdlhammer() {}
-pub fn cdlhangingman() {}
-pub fn s_cdlhangingman() {}
-pub fn cdlharami() {}
-pub fn s_cdlharami() {}
-pub fn cdlharamicross() {}
-pub fn s_cdlharamicross() {}
-pub fn cdlhighwave() {}
-pub fn s_cdlhighwave() {}
-pub fn cdlhikkake() {}
-pub fn s_cdlhikkake() {}
-pub fn cdlhikkakemod() {}
-pub fn s_cdlhikkakemod() {}
-pub fn cdlhomingpigeon() {}
-pub fn s_cdlhomingpigeon() {}
-pub fn cdlidentical3crows() {}
-pub fn s_cdlidentical3crows() {}
-pub fn cdlinneck() {}
-pub fn s_cdlinneck() {}
-pub fn cdlinvertedhammer() {}
-pub fn s_cdlinvertedhammer() {}
-pub fn cdlkicking() {}
-pub fn s_cdlkicking() {}
-pub fn cdlkickingbylength() {}
-pub fn s_cdlkickingbylength() {}
-pub fn cdlladderbottom() {}
-pub fn s_cdlladderbottom() {}
-pub fn cdllongleggeddoji() {}
-pub fn s_cdllongleggeddoji() {}
-pub fn cdllongline() {}
-pub fn s_cdllongline() {}
-pub fn cdlmarubozu() {}
-pub fn s_cdlmarubozu() {}
-pub fn cdlmatchinglow() {}
-pub fn s_cdlmatchinglow() {}
-pub fn cdlmathold() {}
-pub fn s_cdlmathold() {}
-pub fn cdlmorningdojistar() {}
-pub fn s_cdlmorningdojistar() {}
-pub fn cdlmorningstar() {}
-pub fn s_cdlmorningstar() {}
-pub fn cdlonneck() {}
-pub fn s_cdlonneck() {}
-pub fn cdlpiercing() {}
-pub fn s_cdlpiercing() {}
-pub fn cdlrickshawman() {}
-pub fn s_cdlrickshawman() {}
-pub fn cdlrisefall3methods() {}
-pub fn s_cdlrisefall3methods() {}
-pub fn cdlseparatinglines() {}
-pub fn s_cdlseparatinglines() {}
-pub fn cdlshootingstar() {}
-pub fn s_cdlshootingstar() {}
-pub fn cdlshortline() {}
-pub fn s_cdlshortline() {}
-pub fn cdlspinningtop() {}
-pub fn s_cdlspinningtop() {}
-pub fn cdlstalledpattern() {}
-pub fn s_cdlstalledpattern() {}
-pub fn cdlsticksandwich() {}
-pub fn s_cdlsticksandwich() {}
-pub fn cdltakuri() {}
-pub fn s_cdltakuri() {}
-pub fn cdltasukigap() {}
-pub fn s_cdltasukigap() {}
-pub fn cdlthrusting() {}
-pub fn s_cdlthrusting() {}
-pub fn cdltristar() {}
-pub fn s_cdltristar() {}
-pub fn cdlunique3river() {}
-pub fn s_cdlunique3river() {}
-pub fn cdlupsidegap2crows() {}
-pub fn s_cdlupsidegap2crows() {}
-pub fn cdlxsidegap3methods() {}
-pub fn s_cdlxsidegap3methods() {}
-pub fn ceil() {}
-pub fn s_ceil() {}
-pub fn cmo() {}
-pub fn s_cmo() {}
-pub fn correl() {}
-pub fn s_correl() {}
-pub fn cos() {}
-pub fn s_cos() {}
-pub fn cosh() {}
-pub fn s_cosh() {}
-pub fn dema() {}
-pub fn s_dema() {}
-pub fn div() {}
-pub fn s_div() {}
-pub fn dx() {}
-pub fn s_dx() {}
-pub fn ema() {}
-pub fn s_ema() {}
-pub fn exp() {}
-pub fn s_exp() {}
-pub fn floor() {}
-pub fn s_floor() {}
-pub fn ht_dcperiod() {}
-pub fn s_ht_dcperiod() {}
-pub fn ht_dcphase() {}
-pub fn s_ht_dcphase() {}
-pub fn ht_phasor() {}
-pub fn s_ht_phasor() {}
-pub fn ht_sine() {}
-pub fn s_ht_sine() {}
-pub fn ht_trendline() {}
-pub fn s_ht_trendline() {}
-pub fn ht_trendmode() {}
-pub fn s_ht_trendmode() {}
-pub fn imi() {}
-pub fn s_imi() {}
-pub fn kama() {}
-pub fn s_kama() {}
-pub fn linearreg() {}
-pub fn s_linearreg() {}
-pub fn linearreg_angle() {}
-pub fn s_linearreg_angle() {}
-pub fn linearreg_intercept() {}
-pub fn s_linearreg_intercept() {}
-pub fn linearreg_slope() {}
-pub fn s_linearreg_slope() {}
-pub fn ln() {}
-pub fn s_ln() {}
-pub fn log10() {}
-pub fn s_log10() {}
+/// [`aroonosc`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_MA - Moving average
+/// Each buffer needs room for the input length minus [`aroonosc_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn aroonosc_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_AROONOSC_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.aroonosc)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the high vector
+                low.as_ptr(),  // pointer to the low vector
+                timeperiod,    // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`aroonosc`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn aroonosc_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = aroonosc(high, low, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`aroonosc`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn aroonosc_lookback(timeperiod: i32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_AROONOSC_Lookback(timeperiod) })
+}
+
+/// TA_ASIN - Vector Trigonometric ASin
 ///
 /// Input  = double
 /// Output = double
+///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -1076,118 +1420,244 @@ pub fn s_log10() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::ma(10,rust_ta_lib::TA_MAType_TA_MAType_SMA,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::asin(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn ma(
-    period: u32,
-    optInMAType: crate::TA_MAType,
-    close: &Vec<f64>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn asin<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    asin_range(in_real, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_MA(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the ma
-            optInMAType,
-            &mut out_begin,   // set to index of the first close to have an atr value
-            &mut out_size,    // set to number of atr values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`asin`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn asin_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.asin)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
 
+/// [`asin`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_MA - Moving average
+/// Each buffer needs room for the input length minus [`asin_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn asin_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ASIN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.asin)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`asin`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn asin_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = asin(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`asin`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn asin_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_ASIN_Lookback() })
+}
+
+/// TA_ATAN - Vector Trigonometric ATan
 ///
-/// Input  = float
+/// Input  = double
 /// Output = double
+///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::s_ma(10,rust_ta_lib::TA_MAType_TA_MAType_SMA,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::atan(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_ma(
-    period: u32,
-    optInMAType: crate::TA_MAType,
-    close: &Vec<f32>,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn atan<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    atan_range(in_real, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_S_MA(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the ma
-            optInMAType,
-            &mut out_begin,   // set to index of the first close to have an atr value
-            &mut out_size,    // set to number of atr values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`atan`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn atan_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.atan)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`atan`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_MACD - Moving Average Convergence/Divergence
-///
-/// Input  = double
-/// Output = double, double, double
-///
-/// Optional Parameters
-/// -------------------
-/// optInFastPeriod:(From 2 to 100000)
-///    Number of period for the fast MA
+/// Each buffer needs room for the input length minus [`atan_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn atan_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ATAN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.atan)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`atan`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn atan_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = atan(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`atan`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn atan_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_ATAN_Lookback() })
+}
+
+pub fn atr<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    atr_range(period, high, low, close, ..)
+}
+
+/// [`atr`] restricted to the inputs whose index is in `range`
 ///
-/// optInSlowPeriod:(From 2 to 100000)
-///    Number of period for the slow MA
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn atr_range<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.atr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`atr`] writing into caller-provided buffers instead of allocating
 ///
-/// optInSignalPeriod:(From 1 to 100000)
-///    Smoothing for the signal line (nb of period)
+/// Each buffer needs room for the input length minus [`atr_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn atr_into<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(period: int 1..=100000);
+    compute_into(
+        len,
+        || unsafe { crate::TA_ATR_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.atr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the atr
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`atr`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn atr_aligned<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = atr(period, high, low, close)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`atr`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn atr_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ATR_Lookback(period) })
+}
+
+/// TA_AVGPRICE - Average Price
 ///
+/// Input  = double, double, double, double
+/// Output = double
 ///
 /// #Sample
 /// ```
@@ -1196,154 +1666,245 @@ pub fn s_ma(
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (macd,macd_signal,macd_hist, begin) = rust_ta_lib::wrapper::macd(2,5,10,&close_prices);
-/// for (index, value) in macd.iter().enumerate() {
-///        println!("macd index {} = {}", begin + index as i32 + 1, value);
-///        println!("macd_signal index {} = {:?}", begin + index as i32 + 1, macd_signal.get(index));
-///        println!("macd_hist index {} = {:?}", begin + index as i32 + 1, macd_hist.get(index));
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::avgprice(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn macd(
-    fast_period: u32,
-    slow_period: u32,
-    signal_period: u32,
-    close: &Vec<f64>,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut macd: Vec<f64> = Vec::with_capacity(close.len());
-    let mut macd_signal: Vec<f64> = Vec::with_capacity(close.len());
-    let mut macd_hist: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn avgprice<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    avgprice_range(open, high, low, close, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_MACD(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fast_period as i32,     // period of the ma
-            slow_period as i32,
-            signal_period as i32,
-            &mut out_begin,    // set to index of the first close to have an atr value
-            &mut out_size,     // set to number of atr values computed
-            macd.as_mut_ptr(), // pointer to the first element of the output vector
-            macd_signal.as_mut_ptr(), // pointer to the first element of the output vector
-            macd_hist.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                macd.set_len(out_size as usize);
-                macd_signal.set_len(out_size as usize);
-                macd_hist.set_len(out_size as usize)
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`avgprice`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn avgprice_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.avgprice)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (macd, macd_signal, macd_hist, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`avgprice`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_MACD - Moving Average Convergence/Divergence
+/// Each buffer needs room for the input length minus [`avgprice_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn avgprice_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_AVGPRICE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.avgprice)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`avgprice`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn avgprice_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = avgprice(open, high, low, close)?;
+    let len = open.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`avgprice`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn avgprice_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_AVGPRICE_Lookback() })
+}
+
+/// TA_AVGDEV - Average Deviation
 ///
 /// Input  = double
-/// Output = double, double, double
+/// Output = double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInFastPeriod:(From 2 to 100000)
-///    Number of period for the fast MA
-///
-/// optInSlowPeriod:(From 2 to 100000)
-///    Number of period for the slow MA
-///
-/// optInSignalPeriod:(From 1 to 100000)
-///    Smoothing for the signal line (nb of period)
-///
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
 ///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (macd,macd_signal,macd_hist, begin) = rust_ta_lib::wrapper::s_macd(2,5,10,&close_prices);
-/// for (index, value) in macd.iter().enumerate() {
-///        println!("macd index {} = {}", begin + index as i32 + 1, value);
-///        println!("macd_signal index {} = {:?}", begin + index as i32 + 1, macd_signal.get(index));
-///        println!("macd_hist index {} = {:?}", begin + index as i32 + 1, macd_hist.get(index));
+/// let (out, begin) = rust_ta_lib::wrapper::avgdev(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_macd(
-    fast_period: u32,
-    slow_period: u32,
-    signal_period: u32,
-    close: &Vec<f32>,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut macd: Vec<f64> = Vec::with_capacity(close.len());
-    let mut macd_signal: Vec<f64> = Vec::with_capacity(close.len());
-    let mut macd_hist: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn avgdev<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    avgdev_range(in_real, timeperiod, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_S_MACD(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fast_period as i32,     // period of the ma
-            slow_period as i32,
-            signal_period as i32,
-            &mut out_begin,    // set to index of the first close to have an atr value
-            &mut out_size,     // set to number of atr values computed
-            macd.as_mut_ptr(), // pointer to the first element of the output vector
-            macd_signal.as_mut_ptr(), // pointer to the first element of the output vector
-            macd_hist.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                macd.set_len(out_size as usize);
-                macd_signal.set_len(out_size as usize);
-                macd_hist.set_len(out_size as usize)
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`avgdev`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn avgdev_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.avgdev)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (macd, macd_signal, macd_hist, out_begin)
+    Ok((out, out_begin))
 }
-pub fn macdext() {}
-pub fn s_macdext() {}
-pub fn macdfix() {}
-pub fn s_macdfix() {}
+
+/// [`avgdev`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_MAMA - MESA Adaptive Moving Average
+/// Each buffer needs room for the input length minus [`avgdev_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn avgdev_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_AVGDEV_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.avgdev)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`avgdev`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn avgdev_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = avgdev(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`avgdev`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn avgdev_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_AVGDEV_Lookback(timeperiod) })
+}
+
+/// Output of [`bbands`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BBands {
+    /// Upper band
+    pub upper: Vec<f64>,
+    /// Middle band, the moving average
+    pub middle: Vec<f64>,
+    /// Lower band
+    pub lower: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+///
+/// TA_BBANDS - Bollinger Bands
 ///
 /// Input  = double
-/// Output = double, double
+/// Output = double, double, double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInFastLimit:(From 0.01 to 0.99)
-///    Upper limit use in the adaptive algorithm
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
 ///
-/// optInSlowLimit:(From 0.01 to 0.99)
-///    Lower limit use in the adaptive algorithm
+/// optInNbDevUp:(From TA_REAL_MIN to TA_REAL_MAX)
+///    Deviation multiplier for upper band
+///
+/// optInNbDevDn:(From TA_REAL_MIN to TA_REAL_MAX)
+///    Deviation multiplier for lower band
+///
+/// optInMAType:
+///    Type of Moving Average
 ///
 /// #Sample
 /// ```
@@ -1352,204 +1913,13771 @@ pub fn s_macdfix() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs,_, begin) = rust_ta_lib::wrapper::mama( &close_prices,0.2,0.3);
+/// let rust_ta_lib::wrapper::BBands { upper: outs, begin, .. } = rust_ta_lib::wrapper::bbands( 10,&close_prices,0.2,0.3,rust_ta_lib::MaType::Sma).unwrap();
 /// for (index, value) in outs.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn mama(
-    in_real: &Vec<f64>,
-    in_fast_limit: f64,
-    in_flow_limit: f64,
-) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut mama: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut fama: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_MAMA(
-            0,                        // index of the first close to use
-            in_real.len() as i32 - 1, // index of the last close to use
-            in_real.as_ptr(),         // pointer to the first element of the high vector
-            in_fast_limit,            // pointer to the first element of the close vector
-            in_flow_limit,            // period of the atr
-            &mut out_begin,           // set to index of the first close to have an atr value
-            &mut out_size,            // set to number of atr values computed
-            mama.as_mut_ptr(),        // pointer to the first element of the output vector
-            fama.as_mut_ptr(),        // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                mama.set_len(out_size as usize);
-                fama.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
-
-    (mama, fama, out_begin)
+pub fn bbands<T: TaFloat>(
+    period: u32,
+    in_real: &[T],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+) -> Result<BBands, TaError> {
+    bbands_range(
+        period,
+        in_real,
+        in_db_dev_up,
+        in_db_dev_down,
+        in_ma_type,
+        ..,
+    )
 }
+
+/// [`bbands`] restricted to the inputs whose index is in `range`
 ///
-/// TA_S_MAMA - MESA Adaptive Moving Average
-///
-/// Input  = double
-/// Output = double, double
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn bbands_range<T: TaFloat>(
+    period: u32,
+    in_real: &[T],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<BBands, TaError> {
+    param!(period: int 2..=100000);
+    param!(in_db_dev_up: real -3e37..=3e37);
+    param!(in_db_dev_down: real -3e37..=3e37);
+    let ([upper_band, middle_band, lower_band], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [upper_band, middle_band, lower_band]| unsafe {
+            (T::FFI.bbands)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the high vector
+                period,           // pointer to the first element of the low vector
+                in_db_dev_up,     // pointer to the first element of the close vector
+                in_db_dev_down,   // period of the atr
+                in_ma_type.into(),
+                out_begin,
+                out_size,
+                upper_band,
+                middle_band,
+                lower_band,
+            )
+        },
+    )?;
+
+    Ok(BBands {
+        upper: upper_band,
+        middle: middle_band,
+        lower: lower_band,
+        begin: out_begin,
+    })
+}
+
+/// [`bbands`] writing into caller-provided buffers instead of allocating
 ///
-/// Optional Parameters
-/// -------------------
+/// Each buffer needs room for the input length minus [`bbands_lookback`] values. Returns the
+/// begin index and the number of values written.
+#[allow(clippy::too_many_arguments)]
+pub fn bbands_into<T: TaFloat>(
+    period: u32,
+    in_real: &[T],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+    upper: &mut [f64],
+    middle: &mut [f64],
+    lower: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(period: int 2..=100000);
+    param!(in_db_dev_up: real -3e37..=3e37);
+    param!(in_db_dev_down: real -3e37..=3e37);
+    compute_into(
+        in_real.len(),
+        || unsafe {
+            crate::TA_BBANDS_Lookback(period, in_db_dev_up, in_db_dev_down, in_ma_type.into())
+        },
+        [upper, middle, lower],
+        |start, end, out_begin, out_size, [upper_band, middle_band, lower_band]| unsafe {
+            (T::FFI.bbands)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the high vector
+                period,           // pointer to the first element of the low vector
+                in_db_dev_up,     // pointer to the first element of the close vector
+                in_db_dev_down,   // period of the atr
+                in_ma_type.into(),
+                out_begin,
+                out_size,
+                upper_band,
+                middle_band,
+                lower_band,
+            )
+        },
+    )
+}
+
+/// [`bbands`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn bbands_aligned<T: TaFloat>(
+    period: u32,
+    in_real: &[T],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+) -> Result<BBands, TaError> {
+    let out = bbands(period, in_real, in_db_dev_up, in_db_dev_down, in_ma_type)?;
+    let len = in_real.len();
+    Ok(BBands {
+        upper: align(out.upper, out.begin, len, f64::NAN),
+        middle: align(out.middle, out.begin, len, f64::NAN),
+        lower: align(out.lower, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`bbands`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn bbands_compat<T: TaFloat>(
+    period: u32,
+    in_real: &[T],
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+    compatibility: Compatibility,
+) -> Result<BBands, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        bbands(period, in_real, in_db_dev_up, in_db_dev_down, in_ma_type)
+    })
+}
+
+/// Number of input values [`bbands`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn bbands_lookback(
+    period: u32,
+    in_db_dev_up: f64,
+    in_db_dev_down: f64,
+    in_ma_type: MaType,
+) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    param!(in_db_dev_up: real -3e37..=3e37);
+    param!(in_db_dev_down: real -3e37..=3e37);
+    lookback(|| unsafe {
+        crate::TA_BBANDS_Lookback(period, in_db_dev_up, in_db_dev_down, in_ma_type.into())
+    })
+}
+
+/// TA_BETA - Beta
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::beta(&close_prices, &in_real1, 5).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn beta<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    beta_range(in_real0, in_real1, timeperiod, ..)
+}
+
+/// [`beta`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn beta_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    param!(timeperiod: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.beta)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`beta`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`beta_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn beta_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_BETA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.beta)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`beta`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn beta_aligned<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = beta(in_real0, in_real1, timeperiod)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`beta`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn beta_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_BETA_Lookback(timeperiod) })
+}
+
+/// TA_BOP - Balance Of Power
+///
+/// Input  = double, double, double, double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::bop(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn bop<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    bop_range(open, high, low, close, ..)
+}
+
+/// [`bop`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn bop_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.bop)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`bop`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`bop_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn bop_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_BOP_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.bop)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`bop`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn bop_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = bop(open, high, low, close)?;
+    let len = open.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`bop`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn bop_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_BOP_Lookback() })
+}
+
+///
+/// TA_CCI - Commodity Channel Index
+///
+/// `Input`:  high, low, close, timeperiod
+///
+/// `Output`: (1st, 2nd)
+///
+///    1st: output vector(f64)
+///
+///    2nd: the first index of inputs corresponding to an valid output value
+///
+pub fn cci<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    cci_range(high, low, close, timeperiod, ..)
+}
+
+/// [`cci`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cci_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cci)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`cci`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cci_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn cci_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CCI_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cci)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cci`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn cci_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = cci(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`cci`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cci_lookback(timeperiod: i32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_CCI_Lookback(timeperiod) })
+}
+
+/// TA_CDL2CROWS - Two Crows
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl2crows(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl2crows<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl2crows_range(open, high, low, close, ..)
+}
+
+/// [`cdl2crows`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl2crows_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl2crows)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl2crows`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl2crows_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl2crows_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL2CROWS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl2crows)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl2crows`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl2crows_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl2crows(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl2crows`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl2crows_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL2CROWS_Lookback() })
+}
+
+/// TA_CDL3BLACKCROWS - Three Black Crows
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3blackcrows(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3blackcrows<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3blackcrows_range(open, high, low, close, ..)
+}
+
+/// [`cdl3blackcrows`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3blackcrows_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3blackcrows)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3blackcrows`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3blackcrows_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3blackcrows_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3BLACKCROWS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3blackcrows)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3blackcrows`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3blackcrows_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3blackcrows(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3blackcrows`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3blackcrows_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3BLACKCROWS_Lookback() })
+}
+
+/// TA_CDL3INSIDE - Three Inside Up/Down
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3inside(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3inside<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3inside_range(open, high, low, close, ..)
+}
+
+/// [`cdl3inside`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3inside_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3inside)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3inside`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3inside_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3inside_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3INSIDE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3inside)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3inside`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3inside_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3inside(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3inside`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3inside_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3INSIDE_Lookback() })
+}
+
+/// TA_CDL3LINESTRIKE - Three-Line Strike
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3linestrike(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3linestrike<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3linestrike_range(open, high, low, close, ..)
+}
+
+/// [`cdl3linestrike`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3linestrike_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3linestrike)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3linestrike`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3linestrike_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3linestrike_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3LINESTRIKE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3linestrike)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3linestrike`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3linestrike_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3linestrike(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3linestrike`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3linestrike_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3LINESTRIKE_Lookback() })
+}
+
+/// TA_CDL3OUTSIDE - Three Outside Up/Down
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3outside(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3outside<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3outside_range(open, high, low, close, ..)
+}
+
+/// [`cdl3outside`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3outside_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3outside)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3outside`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3outside_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3outside_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3OUTSIDE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3outside)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3outside`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3outside_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3outside(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3outside`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3outside_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3OUTSIDE_Lookback() })
+}
+
+/// TA_CDL3STARSINSOUTH - Three Stars In The South
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3starsinsouth(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3starsinsouth<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3starsinsouth_range(open, high, low, close, ..)
+}
+
+/// [`cdl3starsinsouth`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3starsinsouth_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3starsinsouth)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3starsinsouth`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3starsinsouth_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3starsinsouth_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3STARSINSOUTH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3starsinsouth)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3starsinsouth`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3starsinsouth_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3starsinsouth(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3starsinsouth`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3starsinsouth_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3STARSINSOUTH_Lookback() })
+}
+
+/// TA_CDL3WHITESOLDIERS - Three Advancing White Soldiers
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdl3whitesoldiers(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdl3whitesoldiers<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdl3whitesoldiers_range(open, high, low, close, ..)
+}
+
+/// [`cdl3whitesoldiers`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdl3whitesoldiers_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdl3whitesoldiers)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdl3whitesoldiers`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdl3whitesoldiers_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdl3whitesoldiers_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDL3WHITESOLDIERS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdl3whitesoldiers)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdl3whitesoldiers`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdl3whitesoldiers_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdl3whitesoldiers(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdl3whitesoldiers`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdl3whitesoldiers_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDL3WHITESOLDIERS_Lookback() })
+}
+
+/// TA_CDLABANDONEDBABY - Abandoned Baby
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.3 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlabandonedbaby(&open_prices, &high_prices, &low_prices, &close_prices, 0.3).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlabandonedbaby<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlabandonedbaby_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdlabandonedbaby`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlabandonedbaby_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlabandonedbaby)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlabandonedbaby`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlabandonedbaby_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlabandonedbaby_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLABANDONEDBABY_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlabandonedbaby)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlabandonedbaby`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlabandonedbaby_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlabandonedbaby(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlabandonedbaby`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlabandonedbaby_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLABANDONEDBABY_Lookback(penetration) })
+}
+
+/// TA_CDLADVANCEBLOCK - Advance Block
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdladvanceblock(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdladvanceblock<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdladvanceblock_range(open, high, low, close, ..)
+}
+
+/// [`cdladvanceblock`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdladvanceblock_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdladvanceblock)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdladvanceblock`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdladvanceblock_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdladvanceblock_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLADVANCEBLOCK_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdladvanceblock)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdladvanceblock`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdladvanceblock_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdladvanceblock(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdladvanceblock`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdladvanceblock_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLADVANCEBLOCK_Lookback() })
+}
+
+/// TA_CDLBELTHOLD - Belt-hold
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlbelthold(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlbelthold<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlbelthold_range(open, high, low, close, ..)
+}
+
+/// [`cdlbelthold`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlbelthold_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlbelthold)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlbelthold`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlbelthold_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlbelthold_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLBELTHOLD_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlbelthold)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlbelthold`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlbelthold_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlbelthold(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlbelthold`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlbelthold_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLBELTHOLD_Lookback() })
+}
+
+/// TA_CDLBREAKAWAY - Breakaway
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlbreakaway(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlbreakaway<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlbreakaway_range(open, high, low, close, ..)
+}
+
+/// [`cdlbreakaway`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlbreakaway_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlbreakaway)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlbreakaway`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlbreakaway_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlbreakaway_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLBREAKAWAY_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlbreakaway)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlbreakaway`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlbreakaway_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlbreakaway(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlbreakaway`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlbreakaway_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLBREAKAWAY_Lookback() })
+}
+
+/// TA_CDLCLOSINGMARUBOZU - Closing Marubozu
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlclosingmarubozu(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlclosingmarubozu<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlclosingmarubozu_range(open, high, low, close, ..)
+}
+
+/// [`cdlclosingmarubozu`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlclosingmarubozu_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlclosingmarubozu)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlclosingmarubozu`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlclosingmarubozu_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlclosingmarubozu_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLCLOSINGMARUBOZU_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlclosingmarubozu)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlclosingmarubozu`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlclosingmarubozu_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlclosingmarubozu(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlclosingmarubozu`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlclosingmarubozu_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLCLOSINGMARUBOZU_Lookback() })
+}
+
+/// TA_CDLCONCEALBABYSWALL - Concealing Baby Swallow
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlconcealbabyswall(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlconcealbabyswall<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlconcealbabyswall_range(open, high, low, close, ..)
+}
+
+/// [`cdlconcealbabyswall`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlconcealbabyswall_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlconcealbabyswall)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlconcealbabyswall`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlconcealbabyswall_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlconcealbabyswall_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLCONCEALBABYSWALL_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlconcealbabyswall)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlconcealbabyswall`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlconcealbabyswall_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlconcealbabyswall(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlconcealbabyswall`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlconcealbabyswall_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLCONCEALBABYSWALL_Lookback() })
+}
+
+/// TA_CDLCOUNTERATTACK - Counterattack
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlcounterattack(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlcounterattack<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlcounterattack_range(open, high, low, close, ..)
+}
+
+/// [`cdlcounterattack`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlcounterattack_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlcounterattack)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlcounterattack`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlcounterattack_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlcounterattack_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLCOUNTERATTACK_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlcounterattack)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlcounterattack`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlcounterattack_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlcounterattack(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlcounterattack`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlcounterattack_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLCOUNTERATTACK_Lookback() })
+}
+
+/// TA_CDLDARKCLOUDCOVER - Dark Cloud Cover
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.5 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdldarkcloudcover(&open_prices, &high_prices, &low_prices, &close_prices, 0.5).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdldarkcloudcover<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdldarkcloudcover_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdldarkcloudcover`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdldarkcloudcover_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdldarkcloudcover)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdldarkcloudcover`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdldarkcloudcover_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdldarkcloudcover_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLDARKCLOUDCOVER_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdldarkcloudcover)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdldarkcloudcover`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdldarkcloudcover_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdldarkcloudcover(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdldarkcloudcover`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdldarkcloudcover_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLDARKCLOUDCOVER_Lookback(penetration) })
+}
+
+/// TA_CDLDOJI - Doji
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdldoji(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdldoji<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdldoji_range(open, high, low, close, ..)
+}
+
+/// [`cdldoji`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdldoji_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdldoji)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdldoji`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdldoji_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdldoji_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLDOJI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdldoji)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdldoji`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdldoji_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdldoji(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdldoji`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdldoji_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLDOJI_Lookback() })
+}
+
+/// TA_CDLDOJISTAR - Doji Star
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdldojistar(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdldojistar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdldojistar_range(open, high, low, close, ..)
+}
+
+/// [`cdldojistar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdldojistar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdldojistar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdldojistar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdldojistar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdldojistar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLDOJISTAR_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdldojistar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdldojistar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdldojistar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdldojistar(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdldojistar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdldojistar_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLDOJISTAR_Lookback() })
+}
+
+/// TA_CDLDRAGONFLYDOJI - Dragonfly Doji
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdldragonflydoji(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdldragonflydoji<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdldragonflydoji_range(open, high, low, close, ..)
+}
+
+/// [`cdldragonflydoji`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdldragonflydoji_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdldragonflydoji)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdldragonflydoji`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdldragonflydoji_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdldragonflydoji_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLDRAGONFLYDOJI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdldragonflydoji)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdldragonflydoji`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdldragonflydoji_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdldragonflydoji(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdldragonflydoji`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdldragonflydoji_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLDRAGONFLYDOJI_Lookback() })
+}
+
+/// TA_CDLENGULFING - Engulfing Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlengulfing(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlengulfing<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlengulfing_range(open, high, low, close, ..)
+}
+
+/// [`cdlengulfing`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlengulfing_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlengulfing)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlengulfing`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlengulfing_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlengulfing_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLENGULFING_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlengulfing)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlengulfing`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlengulfing_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlengulfing(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlengulfing`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlengulfing_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLENGULFING_Lookback() })
+}
+
+/// TA_CDLEVENINGDOJISTAR - Evening Doji Star
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.3 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdleveningdojistar(&open_prices, &high_prices, &low_prices, &close_prices, 0.3).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdleveningdojistar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdleveningdojistar_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdleveningdojistar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdleveningdojistar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdleveningdojistar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdleveningdojistar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdleveningdojistar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdleveningdojistar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLEVENINGDOJISTAR_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdleveningdojistar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdleveningdojistar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdleveningdojistar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdleveningdojistar(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdleveningdojistar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdleveningdojistar_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLEVENINGDOJISTAR_Lookback(penetration) })
+}
+
+/// TA_CDLEVENINGSTAR - Evening Star
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.3 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdleveningstar(&open_prices, &high_prices, &low_prices, &close_prices, 0.3).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdleveningstar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdleveningstar_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdleveningstar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdleveningstar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdleveningstar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdleveningstar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdleveningstar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdleveningstar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLEVENINGSTAR_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdleveningstar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdleveningstar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdleveningstar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdleveningstar(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdleveningstar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdleveningstar_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLEVENINGSTAR_Lookback(penetration) })
+}
+
+/// TA_CDLGAPSIDESIDEWHITE - Up/Down-gap side-by-side white lines
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlgapsidesidewhite(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlgapsidesidewhite<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlgapsidesidewhite_range(open, high, low, close, ..)
+}
+
+/// [`cdlgapsidesidewhite`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlgapsidesidewhite_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlgapsidesidewhite)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlgapsidesidewhite`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlgapsidesidewhite_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlgapsidesidewhite_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLGAPSIDESIDEWHITE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlgapsidesidewhite)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlgapsidesidewhite`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlgapsidesidewhite_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlgapsidesidewhite(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlgapsidesidewhite`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlgapsidesidewhite_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLGAPSIDESIDEWHITE_Lookback() })
+}
+
+/// TA_CDLGRAVESTONEDOJI - Gravestone Doji
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlgravestonedoji(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlgravestonedoji<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlgravestonedoji_range(open, high, low, close, ..)
+}
+
+/// [`cdlgravestonedoji`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlgravestonedoji_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlgravestonedoji)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlgravestonedoji`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlgravestonedoji_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlgravestonedoji_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLGRAVESTONEDOJI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlgravestonedoji)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlgravestonedoji`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlgravestonedoji_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlgravestonedoji(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlgravestonedoji`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlgravestonedoji_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLGRAVESTONEDOJI_Lookback() })
+}
+
+/// TA_CDLHAMMER - Hammer
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhammer(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhammer<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhammer_range(open, high, low, close, ..)
+}
+
+/// [`cdlhammer`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhammer_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhammer)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhammer`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhammer_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhammer_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHAMMER_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhammer)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhammer`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhammer_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhammer(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhammer`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhammer_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHAMMER_Lookback() })
+}
+
+/// TA_CDLHANGINGMAN - Hanging Man
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhangingman(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhangingman<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhangingman_range(open, high, low, close, ..)
+}
+
+/// [`cdlhangingman`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhangingman_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhangingman)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhangingman`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhangingman_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhangingman_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHANGINGMAN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhangingman)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhangingman`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhangingman_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhangingman(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhangingman`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhangingman_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHANGINGMAN_Lookback() })
+}
+
+/// TA_CDLHARAMI - Harami Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlharami(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlharami<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlharami_range(open, high, low, close, ..)
+}
+
+/// [`cdlharami`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlharami_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlharami)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlharami`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlharami_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlharami_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHARAMI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlharami)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlharami`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlharami_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlharami(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlharami`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlharami_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHARAMI_Lookback() })
+}
+
+/// TA_CDLHARAMICROSS - Harami Cross Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlharamicross(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlharamicross<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlharamicross_range(open, high, low, close, ..)
+}
+
+/// [`cdlharamicross`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlharamicross_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlharamicross)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlharamicross`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlharamicross_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlharamicross_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHARAMICROSS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlharamicross)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlharamicross`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlharamicross_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlharamicross(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlharamicross`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlharamicross_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHARAMICROSS_Lookback() })
+}
+
+/// TA_CDLHIGHWAVE - High-Wave Candle
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhighwave(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhighwave<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhighwave_range(open, high, low, close, ..)
+}
+
+/// [`cdlhighwave`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhighwave_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhighwave)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhighwave`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhighwave_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhighwave_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHIGHWAVE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhighwave)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhighwave`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhighwave_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhighwave(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhighwave`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhighwave_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHIGHWAVE_Lookback() })
+}
+
+/// TA_CDLHIKKAKE - Hikkake Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhikkake(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhikkake<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhikkake_range(open, high, low, close, ..)
+}
+
+/// [`cdlhikkake`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhikkake_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhikkake)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhikkake`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhikkake_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhikkake_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHIKKAKE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhikkake)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhikkake`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhikkake_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhikkake(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhikkake`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhikkake_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHIKKAKE_Lookback() })
+}
+
+/// TA_CDLHIKKAKEMOD - Modified Hikkake Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhikkakemod(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhikkakemod<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhikkakemod_range(open, high, low, close, ..)
+}
+
+/// [`cdlhikkakemod`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhikkakemod_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhikkakemod)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhikkakemod`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhikkakemod_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhikkakemod_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHIKKAKEMOD_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhikkakemod)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhikkakemod`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhikkakemod_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhikkakemod(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhikkakemod`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhikkakemod_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHIKKAKEMOD_Lookback() })
+}
+
+/// TA_CDLHOMINGPIGEON - Homing Pigeon
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlhomingpigeon(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlhomingpigeon<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlhomingpigeon_range(open, high, low, close, ..)
+}
+
+/// [`cdlhomingpigeon`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlhomingpigeon_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlhomingpigeon)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlhomingpigeon`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlhomingpigeon_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlhomingpigeon_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLHOMINGPIGEON_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlhomingpigeon)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlhomingpigeon`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlhomingpigeon_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlhomingpigeon(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlhomingpigeon`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlhomingpigeon_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLHOMINGPIGEON_Lookback() })
+}
+
+/// TA_CDLIDENTICAL3CROWS - Identical Three Crows
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlidentical3crows(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlidentical3crows<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlidentical3crows_range(open, high, low, close, ..)
+}
+
+/// [`cdlidentical3crows`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlidentical3crows_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlidentical3crows)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlidentical3crows`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlidentical3crows_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlidentical3crows_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLIDENTICAL3CROWS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlidentical3crows)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlidentical3crows`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlidentical3crows_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlidentical3crows(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlidentical3crows`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlidentical3crows_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLIDENTICAL3CROWS_Lookback() })
+}
+
+/// TA_CDLINNECK - In-Neck Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlinneck(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlinneck<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlinneck_range(open, high, low, close, ..)
+}
+
+/// [`cdlinneck`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlinneck_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlinneck)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlinneck`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlinneck_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlinneck_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLINNECK_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlinneck)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlinneck`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlinneck_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlinneck(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlinneck`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlinneck_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLINNECK_Lookback() })
+}
+
+/// TA_CDLINVERTEDHAMMER - Inverted Hammer
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlinvertedhammer(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlinvertedhammer<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlinvertedhammer_range(open, high, low, close, ..)
+}
+
+/// [`cdlinvertedhammer`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlinvertedhammer_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlinvertedhammer)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlinvertedhammer`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlinvertedhammer_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlinvertedhammer_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLINVERTEDHAMMER_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlinvertedhammer)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlinvertedhammer`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlinvertedhammer_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlinvertedhammer(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlinvertedhammer`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlinvertedhammer_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLINVERTEDHAMMER_Lookback() })
+}
+
+/// TA_CDLKICKING - Kicking
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlkicking(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlkicking<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlkicking_range(open, high, low, close, ..)
+}
+
+/// [`cdlkicking`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlkicking_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlkicking)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlkicking`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlkicking_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlkicking_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLKICKING_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlkicking)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlkicking`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlkicking_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlkicking(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlkicking`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlkicking_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLKICKING_Lookback() })
+}
+
+/// TA_CDLKICKINGBYLENGTH - Kicking - bull/bear determined by the longer marubozu
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlkickingbylength(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlkickingbylength<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlkickingbylength_range(open, high, low, close, ..)
+}
+
+/// [`cdlkickingbylength`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlkickingbylength_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlkickingbylength)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlkickingbylength`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlkickingbylength_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlkickingbylength_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLKICKINGBYLENGTH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlkickingbylength)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlkickingbylength`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlkickingbylength_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlkickingbylength(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlkickingbylength`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlkickingbylength_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLKICKINGBYLENGTH_Lookback() })
+}
+
+/// TA_CDLLADDERBOTTOM - Ladder Bottom
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlladderbottom(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlladderbottom<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlladderbottom_range(open, high, low, close, ..)
+}
+
+/// [`cdlladderbottom`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlladderbottom_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlladderbottom)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlladderbottom`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlladderbottom_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlladderbottom_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLLADDERBOTTOM_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlladderbottom)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlladderbottom`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlladderbottom_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlladderbottom(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlladderbottom`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlladderbottom_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLLADDERBOTTOM_Lookback() })
+}
+
+/// TA_CDLLONGLEGGEDDOJI - Long Legged Doji
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdllongleggeddoji(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdllongleggeddoji<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdllongleggeddoji_range(open, high, low, close, ..)
+}
+
+/// [`cdllongleggeddoji`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdllongleggeddoji_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdllongleggeddoji)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdllongleggeddoji`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdllongleggeddoji_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdllongleggeddoji_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLLONGLEGGEDDOJI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdllongleggeddoji)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdllongleggeddoji`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdllongleggeddoji_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdllongleggeddoji(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdllongleggeddoji`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdllongleggeddoji_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLLONGLEGGEDDOJI_Lookback() })
+}
+
+/// TA_CDLLONGLINE - Long Line Candle
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdllongline(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdllongline<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdllongline_range(open, high, low, close, ..)
+}
+
+/// [`cdllongline`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdllongline_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdllongline)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdllongline`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdllongline_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdllongline_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLLONGLINE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdllongline)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdllongline`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdllongline_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdllongline(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdllongline`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdllongline_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLLONGLINE_Lookback() })
+}
+
+/// TA_CDLMARUBOZU - Marubozu
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlmarubozu(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlmarubozu<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlmarubozu_range(open, high, low, close, ..)
+}
+
+/// [`cdlmarubozu`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlmarubozu_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlmarubozu)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlmarubozu`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlmarubozu_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlmarubozu_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLMARUBOZU_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlmarubozu)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlmarubozu`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlmarubozu_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlmarubozu(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlmarubozu`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlmarubozu_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLMARUBOZU_Lookback() })
+}
+
+/// TA_CDLMATCHINGLOW - Matching Low
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlmatchinglow(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlmatchinglow<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlmatchinglow_range(open, high, low, close, ..)
+}
+
+/// [`cdlmatchinglow`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlmatchinglow_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlmatchinglow)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlmatchinglow`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlmatchinglow_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlmatchinglow_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLMATCHINGLOW_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlmatchinglow)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlmatchinglow`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlmatchinglow_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlmatchinglow(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlmatchinglow`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlmatchinglow_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLMATCHINGLOW_Lookback() })
+}
+
+/// TA_CDLMATHOLD - Mat Hold
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.5 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlmathold(&open_prices, &high_prices, &low_prices, &close_prices, 0.5).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlmathold<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlmathold_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdlmathold`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlmathold_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlmathold)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlmathold`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlmathold_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlmathold_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLMATHOLD_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlmathold)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlmathold`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlmathold_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlmathold(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlmathold`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlmathold_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLMATHOLD_Lookback(penetration) })
+}
+
+/// TA_CDLMORNINGDOJISTAR - Morning Doji Star
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.3 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlmorningdojistar(&open_prices, &high_prices, &low_prices, &close_prices, 0.3).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlmorningdojistar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlmorningdojistar_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdlmorningdojistar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlmorningdojistar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlmorningdojistar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlmorningdojistar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlmorningdojistar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlmorningdojistar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLMORNINGDOJISTAR_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlmorningdojistar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlmorningdojistar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlmorningdojistar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlmorningdojistar(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlmorningdojistar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlmorningdojistar_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLMORNINGDOJISTAR_Lookback(penetration) })
+}
+
+/// TA_CDLMORNINGSTAR - Morning Star
+///
+/// `penetration` is the percentage of penetration of a candle within another candle, TA-Lib uses 0.3 by default
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlmorningstar(&open_prices, &high_prices, &low_prices, &close_prices, 0.3).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlmorningstar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlmorningstar_range(open, high, low, close, penetration, ..)
+}
+
+/// [`cdlmorningstar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlmorningstar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlmorningstar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            penetration,
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlmorningstar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlmorningstar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlmorningstar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+    param!(penetration: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLMORNINGSTAR_Lookback(penetration) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlmorningstar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                penetration,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlmorningstar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlmorningstar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    penetration: f64,
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlmorningstar(open, high, low, close, penetration)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlmorningstar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlmorningstar_lookback(penetration: f64) -> Result<usize, TaError> {
+    param!(penetration: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_CDLMORNINGSTAR_Lookback(penetration) })
+}
+
+/// TA_CDLONNECK - On-Neck Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlonneck(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlonneck<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlonneck_range(open, high, low, close, ..)
+}
+
+/// [`cdlonneck`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlonneck_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlonneck)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlonneck`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlonneck_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlonneck_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLONNECK_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlonneck)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlonneck`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlonneck_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlonneck(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlonneck`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlonneck_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLONNECK_Lookback() })
+}
+
+/// TA_CDLPIERCING - Piercing Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlpiercing(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlpiercing<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlpiercing_range(open, high, low, close, ..)
+}
+
+/// [`cdlpiercing`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlpiercing_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlpiercing)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlpiercing`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlpiercing_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlpiercing_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLPIERCING_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlpiercing)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlpiercing`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlpiercing_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlpiercing(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlpiercing`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlpiercing_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLPIERCING_Lookback() })
+}
+
+/// TA_CDLRICKSHAWMAN - Rickshaw Man
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlrickshawman(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlrickshawman<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlrickshawman_range(open, high, low, close, ..)
+}
+
+/// [`cdlrickshawman`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlrickshawman_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlrickshawman)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlrickshawman`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlrickshawman_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlrickshawman_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLRICKSHAWMAN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlrickshawman)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlrickshawman`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlrickshawman_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlrickshawman(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlrickshawman`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlrickshawman_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLRICKSHAWMAN_Lookback() })
+}
+
+/// TA_CDLRISEFALL3METHODS - Rising/Falling Three Methods
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlrisefall3methods(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlrisefall3methods<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlrisefall3methods_range(open, high, low, close, ..)
+}
+
+/// [`cdlrisefall3methods`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlrisefall3methods_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlrisefall3methods)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlrisefall3methods`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlrisefall3methods_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlrisefall3methods_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLRISEFALL3METHODS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlrisefall3methods)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlrisefall3methods`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlrisefall3methods_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlrisefall3methods(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlrisefall3methods`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlrisefall3methods_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLRISEFALL3METHODS_Lookback() })
+}
+
+/// TA_CDLSEPARATINGLINES - Separating Lines
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlseparatinglines(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlseparatinglines<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlseparatinglines_range(open, high, low, close, ..)
+}
+
+/// [`cdlseparatinglines`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlseparatinglines_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlseparatinglines)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlseparatinglines`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlseparatinglines_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlseparatinglines_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSEPARATINGLINES_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlseparatinglines)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlseparatinglines`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlseparatinglines_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlseparatinglines(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlseparatinglines`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlseparatinglines_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSEPARATINGLINES_Lookback() })
+}
+
+/// TA_CDLSHOOTINGSTAR - Shooting Star
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlshootingstar(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlshootingstar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlshootingstar_range(open, high, low, close, ..)
+}
+
+/// [`cdlshootingstar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlshootingstar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlshootingstar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlshootingstar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlshootingstar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlshootingstar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSHOOTINGSTAR_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlshootingstar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlshootingstar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlshootingstar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlshootingstar(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlshootingstar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlshootingstar_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSHOOTINGSTAR_Lookback() })
+}
+
+/// TA_CDLSHORTLINE - Short Line Candle
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlshortline(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlshortline<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlshortline_range(open, high, low, close, ..)
+}
+
+/// [`cdlshortline`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlshortline_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlshortline)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlshortline`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlshortline_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlshortline_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSHORTLINE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlshortline)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlshortline`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlshortline_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlshortline(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlshortline`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlshortline_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSHORTLINE_Lookback() })
+}
+
+/// TA_CDLSPINNINGTOP - Spinning Top
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlspinningtop(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlspinningtop<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlspinningtop_range(open, high, low, close, ..)
+}
+
+/// [`cdlspinningtop`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlspinningtop_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlspinningtop)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlspinningtop`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlspinningtop_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlspinningtop_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSPINNINGTOP_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlspinningtop)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlspinningtop`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlspinningtop_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlspinningtop(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlspinningtop`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlspinningtop_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSPINNINGTOP_Lookback() })
+}
+
+/// TA_CDLSTALLEDPATTERN - Stalled Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlstalledpattern(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlstalledpattern<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlstalledpattern_range(open, high, low, close, ..)
+}
+
+/// [`cdlstalledpattern`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlstalledpattern_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlstalledpattern)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlstalledpattern`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlstalledpattern_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlstalledpattern_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSTALLEDPATTERN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlstalledpattern)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlstalledpattern`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlstalledpattern_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlstalledpattern(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlstalledpattern`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlstalledpattern_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSTALLEDPATTERN_Lookback() })
+}
+
+/// TA_CDLSTICKSANDWICH - Stick Sandwich
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlsticksandwich(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlsticksandwich<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlsticksandwich_range(open, high, low, close, ..)
+}
+
+/// [`cdlsticksandwich`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlsticksandwich_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlsticksandwich)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlsticksandwich`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlsticksandwich_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlsticksandwich_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLSTICKSANDWICH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlsticksandwich)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlsticksandwich`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlsticksandwich_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlsticksandwich(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlsticksandwich`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlsticksandwich_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLSTICKSANDWICH_Lookback() })
+}
+
+/// TA_CDLTAKURI - Takuri (Dragonfly Doji with very long lower shadow)
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdltakuri(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdltakuri<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdltakuri_range(open, high, low, close, ..)
+}
+
+/// [`cdltakuri`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdltakuri_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdltakuri)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdltakuri`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdltakuri_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdltakuri_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLTAKURI_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdltakuri)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdltakuri`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdltakuri_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdltakuri(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdltakuri`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdltakuri_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLTAKURI_Lookback() })
+}
+
+/// TA_CDLTASUKIGAP - Tasuki Gap
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdltasukigap(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdltasukigap<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdltasukigap_range(open, high, low, close, ..)
+}
+
+/// [`cdltasukigap`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdltasukigap_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdltasukigap)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdltasukigap`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdltasukigap_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdltasukigap_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLTASUKIGAP_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdltasukigap)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdltasukigap`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdltasukigap_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdltasukigap(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdltasukigap`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdltasukigap_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLTASUKIGAP_Lookback() })
+}
+
+/// TA_CDLTHRUSTING - Thrusting Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlthrusting(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlthrusting<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlthrusting_range(open, high, low, close, ..)
+}
+
+/// [`cdlthrusting`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlthrusting_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlthrusting)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlthrusting`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlthrusting_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlthrusting_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLTHRUSTING_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlthrusting)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlthrusting`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlthrusting_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlthrusting(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlthrusting`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlthrusting_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLTHRUSTING_Lookback() })
+}
+
+/// TA_CDLTRISTAR - Tristar Pattern
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdltristar(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdltristar<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdltristar_range(open, high, low, close, ..)
+}
+
+/// [`cdltristar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdltristar_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdltristar)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdltristar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdltristar_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdltristar_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLTRISTAR_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdltristar)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdltristar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdltristar_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdltristar(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdltristar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdltristar_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLTRISTAR_Lookback() })
+}
+
+/// TA_CDLUNIQUE3RIVER - Unique 3 River
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlunique3river(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlunique3river<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlunique3river_range(open, high, low, close, ..)
+}
+
+/// [`cdlunique3river`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlunique3river_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlunique3river)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlunique3river`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlunique3river_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlunique3river_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLUNIQUE3RIVER_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlunique3river)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlunique3river`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlunique3river_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlunique3river(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlunique3river`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlunique3river_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLUNIQUE3RIVER_Lookback() })
+}
+
+/// TA_CDLUPSIDEGAP2CROWS - Upside Gap Two Crows
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlupsidegap2crows(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlupsidegap2crows<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlupsidegap2crows_range(open, high, low, close, ..)
+}
+
+/// [`cdlupsidegap2crows`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlupsidegap2crows_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlupsidegap2crows)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlupsidegap2crows`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlupsidegap2crows_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlupsidegap2crows_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLUPSIDEGAP2CROWS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlupsidegap2crows)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlupsidegap2crows`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlupsidegap2crows_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlupsidegap2crows(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlupsidegap2crows`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlupsidegap2crows_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLUPSIDEGAP2CROWS_Lookback() })
+}
+
+/// TA_CDLXSIDEGAP3METHODS - Upside/Downside Gap Three Methods
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (signals, begin) = rust_ta_lib::wrapper::cdlxsidegap3methods(&open_prices, &high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, signal) in signals.iter().enumerate() {
+///        println!("candle index {} = {:?}", begin + index as i32 + 1, signal);
+/// }
+/// ```
+pub fn cdlxsidegap3methods<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    cdlxsidegap3methods_range(open, high, low, close, ..)
+}
+
+/// [`cdlxsidegap3methods`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cdlxsidegap3methods_range<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<CandleSignal>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    candle(len, range, |start, end, out_begin, out_size, out| unsafe {
+        (T::FFI.cdlxsidegap3methods)(
+            start,
+            end,
+            open.as_ptr(),
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            out_begin,
+            out_size,
+            out,
+        )
+    })
+}
+
+/// [`cdlxsidegap3methods`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cdlxsidegap3methods_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn cdlxsidegap3methods_into<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CDLXSIDEGAP3METHODS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cdlxsidegap3methods)(
+                start,
+                end,
+                open.as_ptr(),
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cdlxsidegap3methods`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn cdlxsidegap3methods_aligned<T: TaFloat>(
+    open: &[T],
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<Option<CandleSignal>>, TaError> {
+    let (out, begin) = cdlxsidegap3methods(open, high, low, close)?;
+    let len = open.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`cdlxsidegap3methods`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cdlxsidegap3methods_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CDLXSIDEGAP3METHODS_Lookback() })
+}
+
+/// TA_CEIL - Vector Ceil
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::ceil(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ceil<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ceil_range(in_real, ..)
+}
+
+/// [`ceil`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ceil_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ceil)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ceil`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ceil_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ceil_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CEIL_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ceil)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`ceil`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ceil_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ceil(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ceil`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ceil_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_CEIL_Lookback() })
+}
+
+/// TA_CMO - Chande Momentum Oscillator
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::cmo(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn cmo<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    cmo_range(in_real, timeperiod, ..)
+}
+
+/// [`cmo`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cmo_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cmo)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`cmo`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cmo_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn cmo_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CMO_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cmo)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`cmo`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn cmo_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = cmo(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`cmo`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn cmo_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || cmo(in_real, timeperiod))
+}
+
+/// Number of input values [`cmo`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cmo_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_CMO_Lookback(timeperiod) })
+}
+
+/// TA_CORREL - Pearson's Correlation Coefficient (r)
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::correl(&close_prices, &in_real1, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn correl<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    correl_range(in_real0, in_real1, timeperiod, ..)
+}
+
+/// [`correl`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn correl_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    param!(timeperiod: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.correl)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`correl`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`correl_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn correl_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_CORREL_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.correl)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`correl`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn correl_aligned<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = correl(in_real0, in_real1, timeperiod)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`correl`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn correl_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_CORREL_Lookback(timeperiod) })
+}
+
+/// TA_COS - Vector Trigonometric Cos
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::cos(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn cos<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    cos_range(in_real, ..)
+}
+
+/// [`cos`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cos_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cos)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`cos`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cos_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn cos_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_COS_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cos)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`cos`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn cos_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = cos(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`cos`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cos_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_COS_Lookback() })
+}
+
+/// TA_COSH - Vector Trigonometric Cosh
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::cosh(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn cosh<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    cosh_range(in_real, ..)
+}
+
+/// [`cosh`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn cosh_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cosh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`cosh`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`cosh_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn cosh_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_COSH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.cosh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`cosh`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn cosh_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = cosh(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`cosh`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn cosh_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_COSH_Lookback() })
+}
+
+/// TA_DEMA - Double Exponential Moving Average
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::dema(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn dema<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    dema_range(in_real, timeperiod, ..)
+}
+
+/// [`dema`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn dema_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.dema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`dema`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`dema_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn dema_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_DEMA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.dema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`dema`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn dema_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = dema(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`dema`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn dema_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || dema(in_real, timeperiod))
+}
+
+/// Number of input values [`dema`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn dema_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_DEMA_Lookback(timeperiod) })
+}
+
+/// TA_DIV - Vector Arithmetic Div
+///
+/// Input  = double, double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::div(&close_prices, &in_real1).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn div<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    div_range(in_real0, in_real1, ..)
+}
+
+/// [`div`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn div_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.div)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`div`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`div_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn div_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_DIV_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.div)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`div`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn div_aligned<T: TaFloat>(in_real0: &[T], in_real1: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = div(in_real0, in_real1)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`div`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn div_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_DIV_Lookback() })
+}
+
+/// TA_DX - Directional Movement Index
+///
+/// Input  = double, double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::dx(&high_prices, &low_prices, &close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn dx<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    dx_range(high, low, close, timeperiod, ..)
+}
+
+/// [`dx`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn dx_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.dx)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`dx`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`dx_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn dx_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_DX_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.dx)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`dx`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn dx_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = dx(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`dx`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn dx_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_DX_Lookback(timeperiod) })
+}
+
+/// TA_EMA - Exponential Moving Average
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::ema(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ema<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ema_range(in_real, timeperiod, ..)
+}
+
+/// [`ema`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ema_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ema`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ema_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ema_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_EMA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ema`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ema_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ema(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`ema`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn ema_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || ema(in_real, timeperiod))
+}
+
+/// Number of input values [`ema`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ema_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_EMA_Lookback(timeperiod) })
+}
+
+/// TA_EXP - Vector Arithmetic Exp
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::exp(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn exp<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    exp_range(in_real, ..)
+}
+
+/// [`exp`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn exp_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.exp)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`exp`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`exp_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn exp_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_EXP_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.exp)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`exp`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn exp_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = exp(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`exp`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn exp_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_EXP_Lookback() })
+}
+
+/// TA_FLOOR - Vector Floor
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::floor(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn floor<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    floor_range(in_real, ..)
+}
+
+/// [`floor`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn floor_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.floor)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`floor`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`floor_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn floor_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_FLOOR_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.floor)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`floor`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn floor_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = floor(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`floor`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn floor_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_FLOOR_Lookback() })
+}
+
+/// Output of [`ht_phasor`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtPhasor {
+    /// In-phase component
+    pub inphase: Vec<f64>,
+    /// Quadrature component
+    pub quadrature: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+/// Output of [`ht_sine`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtSine {
+    /// Sine of the dominant cycle phase
+    pub sine: Vec<f64>,
+    /// Sine of the phase advanced by 45 degrees
+    pub leadsine: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+/// TA_HT_DCPERIOD - Hilbert Transform - Dominant Cycle Period
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let (outs, begin) = rust_ta_lib::wrapper::ht_dcperiod(&close_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ht_dcperiod<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ht_dcperiod_range(in_real, ..)
+}
+
+/// [`ht_dcperiod`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_dcperiod_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let ([out], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_dcperiod)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ht_dcperiod`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_dcperiod_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ht_dcperiod_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_DCPERIOD_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_dcperiod)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ht_dcperiod`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ht_dcperiod_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ht_dcperiod(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ht_dcperiod`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_dcperiod_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_DCPERIOD_Lookback() })
+}
+
+/// TA_HT_DCPHASE - Hilbert Transform - Dominant Cycle Phase
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let (outs, begin) = rust_ta_lib::wrapper::ht_dcphase(&close_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ht_dcphase<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ht_dcphase_range(in_real, ..)
+}
+
+/// [`ht_dcphase`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_dcphase_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let ([out], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_dcphase)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ht_dcphase`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_dcphase_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ht_dcphase_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_DCPHASE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_dcphase)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ht_dcphase`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ht_dcphase_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ht_dcphase(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ht_dcphase`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_dcphase_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_DCPHASE_Lookback() })
+}
+
+/// TA_HT_PHASOR - Hilbert Transform - Phasor Components
+///
+/// Input  = double
+/// Output = double, double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let out = rust_ta_lib::wrapper::ht_phasor(&close_prices).unwrap();
+/// for (index, value) in out.inphase.iter().enumerate() {
+///        println!("inphase index {} = {}", out.begin + index as i32 + 1, value);
+///        println!("quadrature index {} = {:?}", out.begin + index as i32 + 1, out.quadrature.get(index));
+///  }
+/// ```
+pub fn ht_phasor<T: TaFloat>(in_real: &[T]) -> Result<HtPhasor, TaError> {
+    ht_phasor_range(in_real, ..)
+}
+
+/// [`ht_phasor`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_phasor_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<HtPhasor, TaError> {
+    let ([inphase, quadrature], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [inphase, quadrature]| unsafe {
+            (T::FFI.ht_phasor)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                inphase,
+                quadrature,
+            )
+        },
+    )?;
+
+    Ok(HtPhasor {
+        inphase,
+        quadrature,
+        begin: out_begin,
+    })
+}
+
+/// [`ht_phasor`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_phasor_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ht_phasor_into<T: TaFloat>(
+    in_real: &[T],
+    inphase: &mut [f64],
+    quadrature: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_PHASOR_Lookback() },
+        [inphase, quadrature],
+        |start, end, out_begin, out_size, [inphase, quadrature]| unsafe {
+            (T::FFI.ht_phasor)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                inphase,
+                quadrature,
+            )
+        },
+    )
+}
+
+/// [`ht_phasor`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ht_phasor_aligned<T: TaFloat>(in_real: &[T]) -> Result<HtPhasor, TaError> {
+    let out = ht_phasor(in_real)?;
+    let len = in_real.len();
+    Ok(HtPhasor {
+        inphase: align(out.inphase, out.begin, len, f64::NAN),
+        quadrature: align(out.quadrature, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`ht_phasor`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_phasor_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_PHASOR_Lookback() })
+}
+
+/// TA_HT_SINE - Hilbert Transform - SineWave
+///
+/// Input  = double
+/// Output = double, double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let out = rust_ta_lib::wrapper::ht_sine(&close_prices).unwrap();
+/// for (index, value) in out.sine.iter().enumerate() {
+///        println!("sine index {} = {}", out.begin + index as i32 + 1, value);
+///        println!("leadsine index {} = {:?}", out.begin + index as i32 + 1, out.leadsine.get(index));
+///  }
+/// ```
+pub fn ht_sine<T: TaFloat>(in_real: &[T]) -> Result<HtSine, TaError> {
+    ht_sine_range(in_real, ..)
+}
+
+/// [`ht_sine`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_sine_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<HtSine, TaError> {
+    let ([sine, leadsine], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [sine, leadsine]| unsafe {
+            (T::FFI.ht_sine)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                sine,
+                leadsine,
+            )
+        },
+    )?;
+
+    Ok(HtSine {
+        sine,
+        leadsine,
+        begin: out_begin,
+    })
+}
+
+/// [`ht_sine`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_sine_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ht_sine_into<T: TaFloat>(
+    in_real: &[T],
+    sine: &mut [f64],
+    leadsine: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_SINE_Lookback() },
+        [sine, leadsine],
+        |start, end, out_begin, out_size, [sine, leadsine]| unsafe {
+            (T::FFI.ht_sine)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                sine,
+                leadsine,
+            )
+        },
+    )
+}
+
+/// [`ht_sine`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ht_sine_aligned<T: TaFloat>(in_real: &[T]) -> Result<HtSine, TaError> {
+    let out = ht_sine(in_real)?;
+    let len = in_real.len();
+    Ok(HtSine {
+        sine: align(out.sine, out.begin, len, f64::NAN),
+        leadsine: align(out.leadsine, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`ht_sine`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_sine_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_SINE_Lookback() })
+}
+
+/// TA_HT_TRENDLINE - Hilbert Transform - Instantaneous Trendline
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let (outs, begin) = rust_ta_lib::wrapper::ht_trendline(&close_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ht_trendline<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ht_trendline_range(in_real, ..)
+}
+
+/// [`ht_trendline`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_trendline_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let ([out], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_trendline)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ht_trendline`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_trendline_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ht_trendline_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_TRENDLINE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_trendline)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ht_trendline`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ht_trendline_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ht_trendline(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ht_trendline`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_trendline_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_TRENDLINE_Lookback() })
+}
+
+/// TA_HT_TRENDMODE - Hilbert Transform - Trend vs Cycle Mode
+///
+/// Input  = double
+/// Output = integer
+///
+/// `true` when the market is trending, `false` when it is in cycle mode
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = (0..100).map(|i| 1.087 + (i as f64 / 5.0).sin() * 0.001).collect();
+/// let (trending, begin) = rust_ta_lib::wrapper::ht_trendmode(&close_prices).unwrap();
+/// for (index, value) in trending.iter().enumerate() {
+///        println!("trend mode index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ht_trendmode<T: TaFloat>(in_real: &[T]) -> Result<(Vec<bool>, crate::TA_Integer), TaError> {
+    ht_trendmode_range(in_real, ..)
+}
+
+/// [`ht_trendmode`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ht_trendmode_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<bool>, crate::TA_Integer), TaError> {
+    let ([out], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_trendmode)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out.into_iter().map(|mode| mode != 0).collect(), out_begin))
+}
+
+/// [`ht_trendmode`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ht_trendmode_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn ht_trendmode_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_HT_TRENDMODE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ht_trendmode)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the input vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ht_trendmode`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn ht_trendmode_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<Option<bool>>, TaError> {
+    let (out, begin) = ht_trendmode(in_real)?;
+    let len = in_real.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`ht_trendmode`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ht_trendmode_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_HT_TRENDMODE_Lookback() })
+}
+
+/// TA_IMI - Intraday Momentum Index
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let open_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0001).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::imi(&open_prices, &close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn imi<T: TaFloat>(
+    open: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    imi_range(open, close, timeperiod, ..)
+}
+
+/// [`imi`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn imi_range<T: TaFloat>(
+    open: &[T],
+    close: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[open.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.imi)(
+                start,
+                end,
+                open.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`imi`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`imi_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn imi_into<T: TaFloat>(
+    open: &[T],
+    close: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[open.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_IMI_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.imi)(
+                start,
+                end,
+                open.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`imi`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn imi_aligned<T: TaFloat>(
+    open: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = imi(open, close, timeperiod)?;
+    let len = open.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`imi`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn imi_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_IMI_Lookback(timeperiod) })
+}
+
+/// TA_KAMA - Kaufman Adaptive Moving Average
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::kama(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn kama<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    kama_range(in_real, timeperiod, ..)
+}
+
+/// [`kama`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn kama_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.kama)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`kama`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`kama_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn kama_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_KAMA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.kama)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`kama`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn kama_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = kama(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`kama`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn kama_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_KAMA_Lookback(timeperiod) })
+}
+
+/// TA_LINEARREG - Linear Regression
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::linearreg(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn linearreg<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    linearreg_range(in_real, timeperiod, ..)
+}
+
+/// [`linearreg`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn linearreg_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`linearreg`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`linearreg_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn linearreg_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LINEARREG_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`linearreg`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn linearreg_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = linearreg(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`linearreg`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn linearreg_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_LINEARREG_Lookback(timeperiod) })
+}
+
+/// TA_LINEARREG_ANGLE - Linear Regression Angle
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::linearreg_angle(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn linearreg_angle<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    linearreg_angle_range(in_real, timeperiod, ..)
+}
+
+/// [`linearreg_angle`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn linearreg_angle_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_angle)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`linearreg_angle`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`linearreg_angle_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn linearreg_angle_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LINEARREG_ANGLE_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_angle)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`linearreg_angle`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn linearreg_angle_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = linearreg_angle(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`linearreg_angle`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn linearreg_angle_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_LINEARREG_ANGLE_Lookback(timeperiod) })
+}
+
+/// TA_LINEARREG_INTERCEPT - Linear Regression Intercept
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::linearreg_intercept(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn linearreg_intercept<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    linearreg_intercept_range(in_real, timeperiod, ..)
+}
+
+/// [`linearreg_intercept`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn linearreg_intercept_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_intercept)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`linearreg_intercept`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`linearreg_intercept_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn linearreg_intercept_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LINEARREG_INTERCEPT_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_intercept)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`linearreg_intercept`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn linearreg_intercept_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = linearreg_intercept(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`linearreg_intercept`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn linearreg_intercept_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_LINEARREG_INTERCEPT_Lookback(timeperiod) })
+}
+
+/// TA_LINEARREG_SLOPE - Linear Regression Slope
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::linearreg_slope(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn linearreg_slope<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    linearreg_slope_range(in_real, timeperiod, ..)
+}
+
+/// [`linearreg_slope`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn linearreg_slope_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_slope)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`linearreg_slope`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`linearreg_slope_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn linearreg_slope_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LINEARREG_SLOPE_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.linearreg_slope)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`linearreg_slope`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn linearreg_slope_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = linearreg_slope(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`linearreg_slope`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn linearreg_slope_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_LINEARREG_SLOPE_Lookback(timeperiod) })
+}
+
+/// TA_LN - Vector Log Natural
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::ln(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ln<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ln_range(in_real, ..)
+}
+
+/// [`ln`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ln_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ln)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ln`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ln_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ln_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ln)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`ln`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ln_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ln(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ln`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ln_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_LN_Lookback() })
+}
+
+/// TA_LOG10 - Vector Log10
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::log10(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn log10<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    log10_range(in_real, ..)
+}
+
+/// [`log10`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn log10_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.log10)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`log10`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`log10_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn log10_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_LOG10_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.log10)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`log10`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn log10_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = log10(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`log10`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn log10_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_LOG10_Lookback() })
+}
+
+///
+/// TA_MA - Moving average
+///
+/// Input  = double
+/// Output = double
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (outs, begin) = rust_ta_lib::wrapper::ma(10,rust_ta_lib::MaType::Sma,&close_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn ma<T: TaFloat>(
+    period: u32,
+    optInMAType: MaType,
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ma_range(period, optInMAType, close, ..)
+}
+
+/// [`ma`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ma_range<T: TaFloat>(
+    period: u32,
+    optInMAType: MaType,
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(period: int 1..=100000);
+    let ([out], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ma)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the ma
+                optInMAType.into(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ma`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ma_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ma_into<T: TaFloat>(
+    period: u32,
+    optInMAType: MaType,
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(period: int 1..=100000);
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_MA_Lookback(period, optInMAType.into()) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ma)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the ma
+                optInMAType.into(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ma`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ma_aligned<T: TaFloat>(
+    period: u32,
+    optInMAType: MaType,
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ma(period, optInMAType, close)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`ma`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn ma_compat<T: TaFloat>(
+    period: u32,
+    optInMAType: MaType,
+    close: &[T],
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || ma(period, optInMAType, close))
+}
+
+/// Number of input values [`ma`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ma_lookback(period: u32, optInMAType: MaType) -> Result<usize, TaError> {
+    param!(period: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MA_Lookback(period, optInMAType.into()) })
+}
+
+/// Output of [`macd`], [`macdext`] and [`macdfix`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Macd {
+    /// MACD line
+    pub macd: Vec<f64>,
+    /// Signal line
+    pub signal: Vec<f64>,
+    /// Histogram, MACD minus signal
+    pub hist: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+///
+/// TA_MACD - Moving Average Convergence/Divergence
+///
+/// Input  = double
+/// Output = double, double, double
+///
+/// Optional Parameters
+/// -------------------
+/// optInFastPeriod:(From 2 to 100000)
+///    Number of period for the fast MA
+///
+/// optInSlowPeriod:(From 2 to 100000)
+///    Number of period for the slow MA
+///
+/// optInSignalPeriod:(From 1 to 100000)
+///    Smoothing for the signal line (nb of period)
+///
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::Macd { macd, signal: macd_signal, hist: macd_hist, begin } = rust_ta_lib::wrapper::macd(2,5,10,&close_prices).unwrap();
+/// for (index, value) in macd.iter().enumerate() {
+///        println!("macd index {} = {}", begin + index as i32 + 1, value);
+///        println!("macd_signal index {} = {:?}", begin + index as i32 + 1, macd_signal.get(index));
+///        println!("macd_hist index {} = {:?}", begin + index as i32 + 1, macd_hist.get(index));
+///  }
+/// ```
+pub fn macd<T: TaFloat>(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[T],
+) -> Result<Macd, TaError> {
+    macd_range(fast_period, slow_period, signal_period, close, ..)
+}
+
+/// [`macd`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn macd_range<T: TaFloat>(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<Macd, TaError> {
+    param!(fast_period: int 2..=100000);
+    param!(slow_period: int 2..=100000);
+    param!(signal_period: int 1..=100000);
+    let ([macd, macd_signal, macd_hist], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [macd, macd_signal, macd_hist]| unsafe {
+            (T::FFI.macd)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fast_period,    // period of the ma
+                slow_period,
+                signal_period,
+                out_begin,
+                out_size,
+                macd,
+                macd_signal,
+                macd_hist,
+            )
+        },
+    )?;
+
+    Ok(Macd {
+        macd,
+        signal: macd_signal,
+        hist: macd_hist,
+        begin: out_begin,
+    })
+}
+
+/// [`macd`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`macd_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn macd_into<T: TaFloat>(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[T],
+    macd: &mut [f64],
+    macdsignal: &mut [f64],
+    macdhist: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(fast_period: int 2..=100000);
+    param!(slow_period: int 2..=100000);
+    param!(signal_period: int 1..=100000);
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_MACD_Lookback(fast_period, slow_period, signal_period) },
+        [macd, macdsignal, macdhist],
+        |start, end, out_begin, out_size, [macd, macd_signal, macd_hist]| unsafe {
+            (T::FFI.macd)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fast_period,    // period of the ma
+                slow_period,
+                signal_period,
+                out_begin,
+                out_size,
+                macd,
+                macd_signal,
+                macd_hist,
+            )
+        },
+    )
+}
+
+/// [`macd`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn macd_aligned<T: TaFloat>(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[T],
+) -> Result<Macd, TaError> {
+    let out = macd(fast_period, slow_period, signal_period, close)?;
+    let len = close.len();
+    Ok(Macd {
+        macd: align(out.macd, out.begin, len, f64::NAN),
+        signal: align(out.signal, out.begin, len, f64::NAN),
+        hist: align(out.hist, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`macd`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn macd_compat<T: TaFloat>(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+    close: &[T],
+    compatibility: Compatibility,
+) -> Result<Macd, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        macd(fast_period, slow_period, signal_period, close)
+    })
+}
+
+/// Number of input values [`macd`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn macd_lookback(
+    fast_period: u32,
+    slow_period: u32,
+    signal_period: u32,
+) -> Result<usize, TaError> {
+    param!(fast_period: int 2..=100000);
+    param!(slow_period: int 2..=100000);
+    param!(signal_period: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MACD_Lookback(fast_period, slow_period, signal_period) })
+}
+
+/// TA_MACDEXT - MACD with controllable MA type
+///
+/// Input  = double
+/// Output = double, double, double
+///
+/// Optional Parameters
+/// -------------------
+/// optInFastPeriod:(From 2 to 100000)
+///    Number of period for the fast MA
+///
+/// optInFastMAType:
+///    Type of Moving Average for fast MA
+///
+/// optInSlowPeriod:(From 2 to 100000)
+///    Number of period for the slow MA
+///
+/// optInSlowMAType:
+///    Type of Moving Average for slow MA
+///
+/// optInSignalPeriod:(From 1 to 100000)
+///    Smoothing for the signal line (nb of period)
+///
+/// optInSignalMAType:
+///    Type of Moving Average for signal line
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::Macd { macd, signal: macdsignal, hist: macdhist, begin } = rust_ta_lib::wrapper::macdext(&close_prices, 12, rust_ta_lib::MaType::Sma, 26, rust_ta_lib::MaType::Sma, 9, rust_ta_lib::MaType::Sma).unwrap();
+/// for (index, value) in macd.iter().enumerate() {
+///        println!("macd index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn macdext<T: TaFloat>(
+    in_real: &[T],
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+) -> Result<Macd, TaError> {
+    macdext_range(
+        in_real,
+        fastperiod,
+        fastmatype,
+        slowperiod,
+        slowmatype,
+        signalperiod,
+        signalmatype,
+        ..,
+    )
+}
+
+/// [`macdext`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+#[allow(clippy::too_many_arguments)]
+pub fn macdext_range<T: TaFloat>(
+    in_real: &[T],
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<Macd, TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    param!(signalperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([macd, macdsignal, macdhist], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [macd, macdsignal, macdhist]| unsafe {
+            (T::FFI.macdext)(
+                start,
+                end,
+                in_real.as_ptr(),
+                fastperiod,
+                fastmatype.into(),
+                slowperiod,
+                slowmatype.into(),
+                signalperiod,
+                signalmatype.into(),
+                out_begin,
+                out_size,
+                macd,
+                macdsignal,
+                macdhist,
+            )
+        },
+    )?;
+
+    Ok(Macd {
+        macd,
+        signal: macdsignal,
+        hist: macdhist,
+        begin: out_begin,
+    })
+}
+
+/// [`macdext`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`macdext_lookback`] values. Returns the
+/// begin index and the number of values written.
+#[allow(clippy::too_many_arguments)]
+pub fn macdext_into<T: TaFloat>(
+    in_real: &[T],
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+    macd: &mut [f64],
+    macdsignal: &mut [f64],
+    macdhist: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    param!(signalperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe {
+            crate::TA_MACDEXT_Lookback(
+                fastperiod,
+                fastmatype.into(),
+                slowperiod,
+                slowmatype.into(),
+                signalperiod,
+                signalmatype.into(),
+            )
+        },
+        [macd, macdsignal, macdhist],
+        |start, end, out_begin, out_size, [macd, macdsignal, macdhist]| unsafe {
+            (T::FFI.macdext)(
+                start,
+                end,
+                in_real.as_ptr(),
+                fastperiod,
+                fastmatype.into(),
+                slowperiod,
+                slowmatype.into(),
+                signalperiod,
+                signalmatype.into(),
+                out_begin,
+                out_size,
+                macd,
+                macdsignal,
+                macdhist,
+            )
+        },
+    )
+}
+
+/// [`macdext`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn macdext_aligned<T: TaFloat>(
+    in_real: &[T],
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+) -> Result<Macd, TaError> {
+    let out = macdext(
+        in_real,
+        fastperiod,
+        fastmatype,
+        slowperiod,
+        slowmatype,
+        signalperiod,
+        signalmatype,
+    )?;
+    let len = in_real.len();
+    Ok(Macd {
+        macd: align(out.macd, out.begin, len, f64::NAN),
+        signal: align(out.signal, out.begin, len, f64::NAN),
+        hist: align(out.hist, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`macdext`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+#[allow(clippy::too_many_arguments)]
+pub fn macdext_compat<T: TaFloat>(
+    in_real: &[T],
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+    compatibility: Compatibility,
+) -> Result<Macd, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        macdext(
+            in_real,
+            fastperiod,
+            fastmatype,
+            slowperiod,
+            slowmatype,
+            signalperiod,
+            signalmatype,
+        )
+    })
+}
+
+/// Number of input values [`macdext`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn macdext_lookback(
+    fastperiod: u32,
+    fastmatype: MaType,
+    slowperiod: u32,
+    slowmatype: MaType,
+    signalperiod: u32,
+    signalmatype: MaType,
+) -> Result<usize, TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    param!(signalperiod: int 1..=100000);
+    lookback(|| unsafe {
+        crate::TA_MACDEXT_Lookback(
+            fastperiod,
+            fastmatype.into(),
+            slowperiod,
+            slowmatype.into(),
+            signalperiod,
+            signalmatype.into(),
+        )
+    })
+}
+
+/// TA_MACDFIX - Moving Average Convergence/Divergence Fix 12/26
+///
+/// Input  = double
+/// Output = double, double, double
+///
+/// Optional Parameters
+/// -------------------
+/// optInSignalPeriod:(From 1 to 100000)
+///    Smoothing for the signal line (nb of period)
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::Macd { macd, signal: macdsignal, hist: macdhist, begin } = rust_ta_lib::wrapper::macdfix(&close_prices, 9).unwrap();
+/// for (index, value) in macd.iter().enumerate() {
+///        println!("macd index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn macdfix<T: TaFloat>(in_real: &[T], signalperiod: u32) -> Result<Macd, TaError> {
+    macdfix_range(in_real, signalperiod, ..)
+}
+
+/// [`macdfix`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn macdfix_range<T: TaFloat>(
+    in_real: &[T],
+    signalperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<Macd, TaError> {
+    param!(signalperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([macd, macdsignal, macdhist], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [macd, macdsignal, macdhist]| unsafe {
+            (T::FFI.macdfix)(
+                start,
+                end,
+                in_real.as_ptr(),
+                signalperiod,
+                out_begin,
+                out_size,
+                macd,
+                macdsignal,
+                macdhist,
+            )
+        },
+    )?;
+
+    Ok(Macd {
+        macd,
+        signal: macdsignal,
+        hist: macdhist,
+        begin: out_begin,
+    })
+}
+
+/// [`macdfix`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`macdfix_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn macdfix_into<T: TaFloat>(
+    in_real: &[T],
+    signalperiod: u32,
+    macd: &mut [f64],
+    macdsignal: &mut [f64],
+    macdhist: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(signalperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MACDFIX_Lookback(signalperiod) },
+        [macd, macdsignal, macdhist],
+        |start, end, out_begin, out_size, [macd, macdsignal, macdhist]| unsafe {
+            (T::FFI.macdfix)(
+                start,
+                end,
+                in_real.as_ptr(),
+                signalperiod,
+                out_begin,
+                out_size,
+                macd,
+                macdsignal,
+                macdhist,
+            )
+        },
+    )
+}
+
+/// [`macdfix`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn macdfix_aligned<T: TaFloat>(in_real: &[T], signalperiod: u32) -> Result<Macd, TaError> {
+    let out = macdfix(in_real, signalperiod)?;
+    let len = in_real.len();
+    Ok(Macd {
+        macd: align(out.macd, out.begin, len, f64::NAN),
+        signal: align(out.signal, out.begin, len, f64::NAN),
+        hist: align(out.hist, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`macdfix`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn macdfix_compat<T: TaFloat>(
+    in_real: &[T],
+    signalperiod: u32,
+    compatibility: Compatibility,
+) -> Result<Macd, TaError> {
+    crate::talib::with_compatibility(compatibility, || macdfix(in_real, signalperiod))
+}
+
+/// Number of input values [`macdfix`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn macdfix_lookback(signalperiod: u32) -> Result<usize, TaError> {
+    param!(signalperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MACDFIX_Lookback(signalperiod) })
+}
+
+/// Output of [`mama`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mama {
+    /// MESA adaptive moving average
+    pub mama: Vec<f64>,
+    /// Following adaptive moving average
+    pub fama: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+///
+/// TA_MAMA - MESA Adaptive Moving Average
+///
+/// Input  = double
+/// Output = double, double
+///
+/// Optional Parameters
+/// -------------------
 /// optInFastLimit:(From 0.01 to 0.99)
 ///    Upper limit use in the adaptive algorithm
 ///
-/// optInSlowLimit:(From 0.01 to 0.99)
-///    Lower limit use in the adaptive algorithm
+/// optInSlowLimit:(From 0.01 to 0.99)
+///    Lower limit use in the adaptive algorithm
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::Mama { mama: outs, begin, .. } = rust_ta_lib::wrapper::mama( &close_prices,0.2,0.3).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn mama<T: TaFloat>(
+    in_real: &[T],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+) -> Result<Mama, TaError> {
+    mama_range(in_real, in_fast_limit, in_flow_limit, ..)
+}
+
+/// [`mama`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn mama_range<T: TaFloat>(
+    in_real: &[T],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<Mama, TaError> {
+    param!(in_fast_limit: real 0.01..=0.99);
+    param!(in_flow_limit: real 0.01..=0.99);
+    let ([mama, fama], out_begin) = compute(
+        in_real.len(),
+        range,
+        |start, end, out_begin, out_size, [mama, fama]| unsafe {
+            (T::FFI.mama)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the high vector
+                in_fast_limit,    // pointer to the first element of the close vector
+                in_flow_limit,    // period of the atr
+                out_begin,
+                out_size,
+                mama,
+                fama,
+            )
+        },
+    )?;
+
+    Ok(Mama {
+        mama,
+        fama,
+        begin: out_begin,
+    })
+}
+
+/// [`mama`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`mama_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn mama_into<T: TaFloat>(
+    in_real: &[T],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+    mama: &mut [f64],
+    fama: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(in_fast_limit: real 0.01..=0.99);
+    param!(in_flow_limit: real 0.01..=0.99);
+    compute_into(
+        in_real.len(),
+        || unsafe { crate::TA_MAMA_Lookback(in_fast_limit, in_flow_limit) },
+        [mama, fama],
+        |start, end, out_begin, out_size, [mama, fama]| unsafe {
+            (T::FFI.mama)(
+                start,
+                end,
+                in_real.as_ptr(), // pointer to the first element of the high vector
+                in_fast_limit,    // pointer to the first element of the close vector
+                in_flow_limit,    // period of the atr
+                out_begin,
+                out_size,
+                mama,
+                fama,
+            )
+        },
+    )
+}
+
+/// [`mama`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn mama_aligned<T: TaFloat>(
+    in_real: &[T],
+    in_fast_limit: f64,
+    in_flow_limit: f64,
+) -> Result<Mama, TaError> {
+    let out = mama(in_real, in_fast_limit, in_flow_limit)?;
+    let len = in_real.len();
+    Ok(Mama {
+        mama: align(out.mama, out.begin, len, f64::NAN),
+        fama: align(out.fama, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`mama`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn mama_lookback(in_fast_limit: f64, in_flow_limit: f64) -> Result<usize, TaError> {
+    param!(in_fast_limit: real 0.01..=0.99);
+    param!(in_flow_limit: real 0.01..=0.99);
+    lookback(|| unsafe { crate::TA_MAMA_Lookback(in_fast_limit, in_flow_limit) })
+}
+
+/// TA_MAVP - Moving average with variable period
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInMinPeriod:(From 2 to 100000)
+///    Value less than minimum will be changed to Minimum period
+///
+/// optInMaxPeriod:(From 2 to 100000)
+///    Value higher than maximum will be changed to Maximum period
+///
+/// optInMAType:
+///    Type of Moving Average
+///
+/// `in_periods` holds the period to use for each bar of `in_real`, values outside
+/// `minperiod..=maxperiod` are clamped to the bounds
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let periods: Vec<f64> = close_prices.iter().enumerate().map(|(i, _)| (2 + i % 5) as f64).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::mavp(&close_prices, &periods, 2, 30, rust_ta_lib::MaType::Sma).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn mavp<T: TaFloat>(
+    in_real: &[T],
+    in_periods: &[T],
+    minperiod: u32,
+    maxperiod: u32,
+    matype: MaType,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    mavp_range(in_real, in_periods, minperiod, maxperiod, matype, ..)
+}
+
+/// [`mavp`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn mavp_range<T: TaFloat>(
+    in_real: &[T],
+    in_periods: &[T],
+    minperiod: u32,
+    maxperiod: u32,
+    matype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real.len(), in_periods.len()])?;
+    param!(minperiod: int 2..=100000);
+    param!(maxperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mavp)(
+                start,
+                end,
+                in_real.as_ptr(),
+                in_periods.as_ptr(),
+                minperiod,
+                maxperiod,
+                matype.into(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`mavp`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`mavp_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn mavp_into<T: TaFloat>(
+    in_real: &[T],
+    in_periods: &[T],
+    minperiod: u32,
+    maxperiod: u32,
+    matype: MaType,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real.len(), in_periods.len()])?;
+    param!(minperiod: int 2..=100000);
+    param!(maxperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MAVP_Lookback(minperiod, maxperiod, matype.into()) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mavp)(
+                start,
+                end,
+                in_real.as_ptr(),
+                in_periods.as_ptr(),
+                minperiod,
+                maxperiod,
+                matype.into(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`mavp`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn mavp_aligned<T: TaFloat>(
+    in_real: &[T],
+    in_periods: &[T],
+    minperiod: u32,
+    maxperiod: u32,
+    matype: MaType,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = mavp(in_real, in_periods, minperiod, maxperiod, matype)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`mavp`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn mavp_compat<T: TaFloat>(
+    in_real: &[T],
+    in_periods: &[T],
+    minperiod: u32,
+    maxperiod: u32,
+    matype: MaType,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        mavp(in_real, in_periods, minperiod, maxperiod, matype)
+    })
+}
+
+/// Number of input values [`mavp`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn mavp_lookback(minperiod: u32, maxperiod: u32, matype: MaType) -> Result<usize, TaError> {
+    param!(minperiod: int 2..=100000);
+    param!(maxperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MAVP_Lookback(minperiod, maxperiod, matype.into()) })
+}
+
+/// TA_MAX - Highest value over a specified period
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::max(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn max<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    max_range(in_real, timeperiod, ..)
+}
+
+/// [`max`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn max_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.max)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`max`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`max_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn max_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MAX_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.max)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`max`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn max_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = max(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`max`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn max_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MAX_Lookback(timeperiod) })
+}
+
+/// TA_MAXINDEX - Index of highest value over a specified period
+///
+/// Input  = double
+/// Output = usize
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// The output values are positions in the input, `in_real[value]` is the extreme value of the window
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::maxindex(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn maxindex<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<usize>, crate::TA_Integer), TaError> {
+    maxindex_range(in_real, timeperiod, ..)
+}
+
+/// [`maxindex`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn maxindex_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<usize>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.maxindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((
+        out.into_iter().map(|index| index as usize).collect(),
+        out_begin,
+    ))
+}
+
+/// [`maxindex`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`maxindex_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn maxindex_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MAXINDEX_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.maxindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`maxindex`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn maxindex_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<Vec<Option<usize>>, TaError> {
+    let (out, begin) = maxindex(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`maxindex`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn maxindex_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MAXINDEX_Lookback(timeperiod) })
+}
+
+/// TA_MEDPRICE - Median Price
+///
+/// Input  = double, double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::medprice(&high_prices, &low_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn medprice<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    medprice_range(high, low, ..)
+}
+
+/// [`medprice`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn medprice_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.medprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`medprice`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`medprice_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn medprice_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MEDPRICE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.medprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`medprice`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn medprice_aligned<T: TaFloat>(high: &[T], low: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = medprice(high, low)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`medprice`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn medprice_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_MEDPRICE_Lookback() })
+}
+
+///
+/// TA_MFI - Money Flow Index
+///
+/// `Input`:  high, low, close, volume, timeperiod
+///
+/// `Output`: (1st, 2nd)
+///
+///    1st: output vector(f64)
+///
+///    2nd: the first index of inputs corresponding to an valid output value
+///
+pub fn mfi<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    timeperiod: i32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    mfi_range(high, low, close, volume, timeperiod, ..)
+}
+
+/// [`mfi`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn mfi_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    timeperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mfi)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the high vector
+                low.as_ptr(),    // pointer to the low vector
+                close.as_ptr(),  // pointer to the close vector
+                volume.as_ptr(), // pointer to the volume vector
+                timeperiod,      // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`mfi`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`mfi_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn mfi_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    timeperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len(), volume.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MFI_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mfi)(
+                start,
+                end,
+                high.as_ptr(),   // pointer to the high vector
+                low.as_ptr(),    // pointer to the low vector
+                close.as_ptr(),  // pointer to the close vector
+                volume.as_ptr(), // pointer to the volume vector
+                timeperiod,      // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`mfi`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn mfi_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    volume: &[T],
+    timeperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = mfi(high, low, close, volume, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`mfi`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn mfi_lookback(timeperiod: i32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MFI_Lookback(timeperiod) })
+}
+
+/// TA_MIDPOINT - MidPoint over period
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::midpoint(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn midpoint<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    midpoint_range(in_real, timeperiod, ..)
+}
+
+/// [`midpoint`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn midpoint_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.midpoint)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`midpoint`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`midpoint_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn midpoint_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MIDPOINT_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.midpoint)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`midpoint`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn midpoint_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = midpoint(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`midpoint`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn midpoint_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MIDPOINT_Lookback(timeperiod) })
+}
+
+/// TA_MIDPRICE - Midpoint Price over period
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::midprice(&high_prices, &low_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn midprice<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    midprice_range(high, low, timeperiod, ..)
+}
+
+/// [`midprice`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn midprice_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.midprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`midprice`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`midprice_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn midprice_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MIDPRICE_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.midprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`midprice`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn midprice_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = midprice(high, low, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`midprice`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn midprice_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MIDPRICE_Lookback(timeperiod) })
+}
+
+/// TA_MIN - Lowest value over a specified period
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::min(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn min<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    min_range(in_real, timeperiod, ..)
+}
+
+/// [`min`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn min_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.min)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`min`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`min_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn min_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MIN_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.min)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`min`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn min_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = min(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`min`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn min_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MIN_Lookback(timeperiod) })
+}
+
+/// TA_MININDEX - Index of lowest value over a specified period
+///
+/// Input  = double
+/// Output = usize
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// The output values are positions in the input, `in_real[value]` is the extreme value of the window
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::minindex(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn minindex<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<usize>, crate::TA_Integer), TaError> {
+    minindex_range(in_real, timeperiod, ..)
+}
+
+/// [`minindex`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn minindex_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<usize>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((
+        out.into_iter().map(|index| index as usize).collect(),
+        out_begin,
+    ))
+}
+
+/// [`minindex`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`minindex_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn minindex_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MININDEX_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`minindex`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn minindex_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<Vec<Option<usize>>, TaError> {
+    let (out, begin) = minindex(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align_some(out, begin, len))
+}
+
+/// Number of input values [`minindex`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn minindex_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MININDEX_Lookback(timeperiod) })
+}
+
+/// Output of [`minmax`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinMax {
+    /// Lowest value over the period
+    pub min: Vec<f64>,
+    /// Highest value over the period
+    pub max: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+/// TA_MINMAX - Lowest and highest values over a specified period
+///
+/// Input  = double
+/// Output = double, double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::MinMax { min, max, begin } = rust_ta_lib::wrapper::minmax(&close_prices, 30).unwrap();
+/// for (index, value) in min.iter().enumerate() {
+///        println!("min index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn minmax<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<MinMax, TaError> {
+    minmax_range(in_real, timeperiod, ..)
+}
+
+/// [`minmax`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn minmax_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<MinMax, TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([min, max], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [min, max]| unsafe {
+            (T::FFI.minmax)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                min,
+                max,
+            )
+        },
+    )?;
+
+    Ok(MinMax {
+        min,
+        max,
+        begin: out_begin,
+    })
+}
+
+/// [`minmax`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`minmax_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn minmax_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    min: &mut [f64],
+    max: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MINMAX_Lookback(timeperiod) },
+        [min, max],
+        |start, end, out_begin, out_size, [min, max]| unsafe {
+            (T::FFI.minmax)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                min,
+                max,
+            )
+        },
+    )
+}
+
+/// [`minmax`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn minmax_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<MinMax, TaError> {
+    let out = minmax(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(MinMax {
+        min: align(out.min, out.begin, len, f64::NAN),
+        max: align(out.max, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`minmax`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn minmax_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MINMAX_Lookback(timeperiod) })
+}
+
+/// Output of [`minmaxindex`], `I` is `Option<usize>` for [`minmaxindex_aligned`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinMaxIndex<I = usize> {
+    /// Index of the lowest value over the period
+    pub min: Vec<I>,
+    /// Index of the highest value over the period
+    pub max: Vec<I>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+/// TA_MINMAXINDEX - Indexes of lowest and highest values over a specified period
+///
+/// Input  = double
+/// Output = usize, usize
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
+/// The output values are positions in the input, `in_real[minidx[i]]` and `in_real[maxidx[i]]`
+/// are the extreme values of the window
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let rust_ta_lib::wrapper::MinMaxIndex { min: minidx, max: maxidx, begin } = rust_ta_lib::wrapper::minmaxindex(&close_prices, 30).unwrap();
+/// for (index, value) in minidx.iter().enumerate() {
+///        println!("minidx index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn minmaxindex<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<MinMaxIndex, TaError> {
+    minmaxindex_range(in_real, timeperiod, ..)
+}
+
+/// [`minmaxindex`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn minmaxindex_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<MinMaxIndex, TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([minidx, maxidx], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [minidx, maxidx]| unsafe {
+            (T::FFI.minmaxindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                minidx,
+                maxidx,
+            )
+        },
+    )?;
+
+    Ok(MinMaxIndex {
+        min: minidx.into_iter().map(|index| index as usize).collect(),
+        max: maxidx.into_iter().map(|index| index as usize).collect(),
+        begin: out_begin,
+    })
+}
+
+/// [`minmaxindex`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`minmaxindex_lookback`] values. Returns the
+/// begin index and the number of values written.
+///
+/// The buffers receive the raw `TA_Integer` values written by TA-Lib.
+pub fn minmaxindex_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    minidx: &mut [crate::TA_Integer],
+    maxidx: &mut [crate::TA_Integer],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MINMAXINDEX_Lookback(timeperiod) },
+        [minidx, maxidx],
+        |start, end, out_begin, out_size, [minidx, maxidx]| unsafe {
+            (T::FFI.minmaxindex)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                minidx,
+                maxidx,
+            )
+        },
+    )
+}
+
+/// [`minmaxindex`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are `None`
+pub fn minmaxindex_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<MinMaxIndex<Option<usize>>, TaError> {
+    let out = minmaxindex(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(MinMaxIndex {
+        min: align_some(out.min, out.begin, len),
+        max: align_some(out.max, out.begin, len),
+        begin: out.begin,
+    })
+}
+
+/// Number of input values [`minmaxindex`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn minmaxindex_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_MINMAXINDEX_Lookback(timeperiod) })
+}
+
+/// TA_MINUS_DI - Minus Directional Indicator
+///
+/// Input  = double, double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::minus_di(&high_prices, &low_prices, &close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn minus_di<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    minus_di_range(high, low, close, timeperiod, ..)
+}
+
+/// [`minus_di`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn minus_di_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minus_di)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`minus_di`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`minus_di_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn minus_di_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MINUS_DI_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minus_di)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`minus_di`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn minus_di_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = minus_di(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`minus_di`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn minus_di_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MINUS_DI_Lookback(timeperiod) })
+}
+
+///
+/// TA_MINUS_DM - Minus Directional Movement
+///
+/// Input  = High, Low
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices = close_prices.clone();
+/// let low_prices = close_prices.clone();
+/// let (outs, begin) = rust_ta_lib::wrapper::minus_dm( 10,&high_prices,&low_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn minus_dm<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    minus_dm_range(period, high, low, ..)
+}
+
+/// [`minus_dm`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn minus_dm_range<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(period: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minus_dm)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the first element of the high vector
+                low.as_ptr(),  // pointer to the first element of the low vector
+                period,        // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`minus_dm`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`minus_dm_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn minus_dm_into<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(period: int 1..=100000);
+    compute_into(
+        len,
+        || unsafe { crate::TA_MINUS_DM_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.minus_dm)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the first element of the high vector
+                low.as_ptr(),  // pointer to the first element of the low vector
+                period,        // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`minus_dm`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn minus_dm_aligned<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = minus_dm(period, high, low)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`minus_dm`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn minus_dm_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MINUS_DM_Lookback(period) })
+}
+
+/// TA_MOM - Momentum
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::mom(&close_prices, 10).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn mom<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    mom_range(in_real, timeperiod, ..)
+}
+
+/// [`mom`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn mom_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mom)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`mom`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`mom_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn mom_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MOM_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mom)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`mom`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn mom_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = mom(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`mom`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn mom_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_MOM_Lookback(timeperiod) })
+}
+
+/// TA_MULT - Vector Arithmetic Mult
+///
+/// Input  = double, double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::mult(&close_prices, &in_real1).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn mult<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    mult_range(in_real0, in_real1, ..)
+}
+
+/// [`mult`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn mult_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mult)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`mult`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`mult_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn mult_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_MULT_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.mult)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`mult`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn mult_aligned<T: TaFloat>(in_real0: &[T], in_real1: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = mult(in_real0, in_real1)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`mult`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn mult_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_MULT_Lookback() })
+}
+
+///
+/// TA_NATR - Normalized Average True Range
+///
+/// `Input`:  high, low, close, timeperiod
+///
+/// `Output`: (1st, 2nd)
+///
+///    1st: output vector(f64)
+///
+///    2nd: the first index of inputs corresponding to an valid output value
+///
+pub fn natr<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    natr_range(high, low, close, timeperiod, ..)
+}
+
+/// [`natr`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn natr_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.natr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`natr`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`natr_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn natr_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_NATR_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.natr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`natr`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn natr_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = natr(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`natr`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn natr_lookback(timeperiod: i32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_NATR_Lookback(timeperiod) })
+}
+
+///
+/// TA_OBV - On Balance Volume
+///
+/// Input  = double
+/// Output = double
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let volumes = close_prices.clone();
+/// let (outs, begin) = rust_ta_lib::wrapper::obv(&close_prices,&volumes).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn obv<T: TaFloat>(
+    close: &[T],
+    volume: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    obv_range(close, volume, ..)
+}
+
+/// [`obv`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn obv_range<T: TaFloat>(
+    close: &[T],
+    volume: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[close.len(), volume.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.obv)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                volume.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`obv`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`obv_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn obv_into<T: TaFloat>(
+    close: &[T],
+    volume: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[close.len(), volume.len()])?;
+    compute_into(
+        len,
+        || unsafe { crate::TA_OBV_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.obv)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                volume.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`obv`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn obv_aligned<T: TaFloat>(close: &[T], volume: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = obv(close, volume)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`obv`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn obv_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_OBV_Lookback() })
+}
+
+/// TA_PLUS_DI - Plus Directional Indicator
+///
+/// Input  = double, double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::plus_di(&high_prices, &low_prices, &close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn plus_di<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    plus_di_range(high, low, close, timeperiod, ..)
+}
+
+/// [`plus_di`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn plus_di_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.plus_di)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`plus_di`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`plus_di_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn plus_di_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_PLUS_DI_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.plus_di)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`plus_di`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn plus_di_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: u32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = plus_di(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`plus_di`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn plus_di_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_PLUS_DI_Lookback(timeperiod) })
+}
+
+///
+/// TA_PLUS_DM - Plus Directional Movement
+///
+/// Input  = High, Low
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices = close_prices.clone();
+/// let low_prices = close_prices.clone();
+/// let (outs, begin) = rust_ta_lib::wrapper::plus_dm( 10,&high_prices,&low_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn plus_dm<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    plus_dm_range(period, high, low, ..)
+}
+
+/// [`plus_dm`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn plus_dm_range<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(period: int 1..=100000);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.plus_dm)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the first element of the high vector
+                low.as_ptr(),  // pointer to the first element of the low vector
+                period,        // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`plus_dm`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`plus_dm_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn plus_dm_into<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(period: int 1..=100000);
+    compute_into(
+        len,
+        || unsafe { crate::TA_PLUS_DM_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.plus_dm)(
+                start,
+                end,
+                high.as_ptr(), // pointer to the first element of the high vector
+                low.as_ptr(),  // pointer to the first element of the low vector
+                period,        // pointer to the first element of the close vector
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`plus_dm`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn plus_dm_aligned<T: TaFloat>(
+    period: u32,
+    high: &[T],
+    low: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = plus_dm(period, high, low)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`plus_dm`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn plus_dm_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 1..=100000);
+    lookback(|| unsafe { crate::TA_PLUS_DM_Lookback(period) })
+}
+
+///
+/// TA_PPO - Percentage Price Oscillator
+///
+/// `Input`  = close, fastperiod(12), slowperiod(26), matype
+///
+/// `Output`: (1st, 2nd)
+///
+///    1st: output vector(f64)
+///
+///    2nd: the first index of inputs corresponding to an valid output value
+///
+pub fn ppo<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ppo_range(close, fastperiod, slowperiod, matype, ..)
+}
+
+/// [`ppo`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ppo_range<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    let ([out], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ppo)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastperiod,     // fast period (suggest default 12)
+                slowperiod,     // slow period (suggest default 26)
+                matype.into(),  // MA Type
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`ppo`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`ppo_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ppo_into<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_PPO_Lookback(fastperiod, slowperiod, matype.into()) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ppo)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastperiod,     // fast period (suggest default 12)
+                slowperiod,     // slow period (suggest default 26)
+                matype.into(),  // MA Type
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ppo`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ppo_aligned<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ppo(close, fastperiod, slowperiod, matype)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`ppo`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn ppo_compat<T: TaFloat>(
+    close: &[T],
+    fastperiod: i32,
+    slowperiod: i32,
+    matype: MaType,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || ppo(close, fastperiod, slowperiod, matype))
+}
+
+/// Number of input values [`ppo`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ppo_lookback(fastperiod: i32, slowperiod: i32, matype: MaType) -> Result<usize, TaError> {
+    param!(fastperiod: int 2..=100000);
+    param!(slowperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_PPO_Lookback(fastperiod, slowperiod, matype.into()) })
+}
+
+/// TA_ROC - Rate of change : ((price/prevPrice)-1)*100
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::roc(&close_prices, 10).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn roc<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    roc_range(in_real, timeperiod, ..)
+}
+
+/// [`roc`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn roc_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.roc)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`roc`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`roc_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn roc_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ROC_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.roc)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`roc`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn roc_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = roc(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`roc`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn roc_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ROC_Lookback(timeperiod) })
+}
+
+/// TA_ROCP - Rate of change Percentage: (price-prevPrice)/prevPrice
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::rocp(&close_prices, 10).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn rocp<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    rocp_range(in_real, timeperiod, ..)
+}
+
+/// [`rocp`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn rocp_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocp)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`rocp`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`rocp_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn rocp_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ROCP_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocp)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`rocp`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn rocp_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = rocp(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`rocp`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn rocp_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ROCP_Lookback(timeperiod) })
+}
+
+/// TA_ROCR - Rate of change ratio: (price/prevPrice)
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::rocr(&close_prices, 10).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn rocr<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    rocr_range(in_real, timeperiod, ..)
+}
+
+/// [`rocr`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn rocr_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocr)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`rocr`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`rocr_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn rocr_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ROCR_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocr)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`rocr`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn rocr_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = rocr(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`rocr`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn rocr_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ROCR_Lookback(timeperiod) })
+}
+
+/// TA_ROCR100 - Rate of change ratio 100 scale: (price/prevPrice)*100
+///
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::rocr100(&close_prices, 10).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn rocr100<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    rocr100_range(in_real, timeperiod, ..)
+}
+
+/// [`rocr100`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn rocr100_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocr100)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`rocr100`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`rocr100_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn rocr100_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ROCR100_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rocr100)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`rocr100`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn rocr100_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = rocr100(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`rocr100`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn rocr100_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ROCR100_Lookback(timeperiod) })
+}
+
+///
+/// TA_RSI - Relative Strength Index
+///
+/// Input  = double
+/// Output = double
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (outs, begin) = rust_ta_lib::wrapper::rsi(10,&close_prices).unwrap();
+/// for (index, value) in outs.iter().enumerate() {
+///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn rsi<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+) -> Result<(Vec<crate::TA_Real>, crate::TA_Integer), TaError> {
+    rsi_range(period, close_prices, ..)
+}
+
+/// [`rsi`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn rsi_range<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<crate::TA_Real>, crate::TA_Integer), TaError> {
+    param!(period: int 2..=100000);
+    let ([out], out_begin) = compute(
+        close_prices.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rsi)(
+                start,
+                end,
+                close_prices.as_ptr(), // pointer to the first element of the vector
+                period,                // period of the rsi
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`rsi`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`rsi_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn rsi_into<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(period: int 2..=100000);
+    compute_into(
+        close_prices.len(),
+        || unsafe { crate::TA_RSI_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.rsi)(
+                start,
+                end,
+                close_prices.as_ptr(), // pointer to the first element of the vector
+                period,                // period of the rsi
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`rsi`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn rsi_aligned<T: TaFloat>(period: u32, close_prices: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = rsi(period, close_prices)?;
+    let len = close_prices.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`rsi`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn rsi_compat<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+    compatibility: Compatibility,
+) -> Result<(Vec<crate::TA_Real>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || rsi(period, close_prices))
+}
+
+/// Number of input values [`rsi`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn rsi_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    lookback(|| unsafe { crate::TA_RSI_Lookback(period) })
+}
+
+/// TA_SAR - Parabolic SAR
+///
+/// Input  = double, double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInAcceleration:(From 0.0)
+///    Acceleration Factor used up to the Maximum value
+///
+/// optInMaximum:(From 0.0)
+///    Acceleration Factor Maximum value
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::sar(&high_prices, &low_prices, 0.02, 0.2).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn sar<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    acceleration: f64,
+    maximum: f64,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sar_range(high, low, acceleration, maximum, ..)
+}
+
+/// [`sar`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sar_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    acceleration: f64,
+    maximum: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(acceleration: real 0.0..=3e37);
+    param!(maximum: real 0.0..=3e37);
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sar)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                acceleration,
+                maximum,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sar`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`sar_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sar_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    acceleration: f64,
+    maximum: f64,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    param!(acceleration: real 0.0..=3e37);
+    param!(maximum: real 0.0..=3e37);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_SAR_Lookback(acceleration, maximum) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sar)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                acceleration,
+                maximum,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`sar`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sar_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    acceleration: f64,
+    maximum: f64,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sar(high, low, acceleration, maximum)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sar`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sar_lookback(acceleration: f64, maximum: f64) -> Result<usize, TaError> {
+    param!(acceleration: real 0.0..=3e37);
+    param!(maximum: real 0.0..=3e37);
+    lookback(|| unsafe { crate::TA_SAR_Lookback(acceleration, maximum) })
+}
+
+/// Optional parameters of [`sarext`]
+///
+/// `Default` gives the TA-Lib defaults.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SarExtOptions {
+    /// Start value and direction. 0 for Auto, >0 for Long, <0 for Short
+    pub start_value: f64,
+    /// Percent offset added/removed to initial stop on short/long reversal
+    pub offset_on_reverse: f64,
+    /// Acceleration Factor initial value for the Long direction
+    pub acceleration_init_long: f64,
+    /// Acceleration Factor for the Long direction
+    pub acceleration_long: f64,
+    /// Acceleration Factor maximum value for the Long direction
+    pub acceleration_max_long: f64,
+    /// Acceleration Factor initial value for the Short direction
+    pub acceleration_init_short: f64,
+    /// Acceleration Factor for the Short direction
+    pub acceleration_short: f64,
+    /// Acceleration Factor maximum value for the Short direction
+    pub acceleration_max_short: f64,
+}
+
+impl Default for SarExtOptions {
+    fn default() -> Self {
+        SarExtOptions {
+            start_value: 0.0,
+            offset_on_reverse: 0.0,
+            acceleration_init_long: 0.02,
+            acceleration_long: 0.02,
+            acceleration_max_long: 0.2,
+            acceleration_init_short: 0.02,
+            acceleration_short: 0.02,
+            acceleration_max_short: 0.2,
+        }
+    }
+}
+
+impl SarExtOptions {
+    // Checks every option against the range documented by TA-Lib
+    fn validate(&self) -> Result<(), TaError> {
+        let SarExtOptions {
+            start_value,
+            offset_on_reverse,
+            acceleration_init_long,
+            acceleration_long,
+            acceleration_max_long,
+            acceleration_init_short,
+            acceleration_short,
+            acceleration_max_short,
+        } = *self;
+        param!(start_value: real -3e37..=3e37);
+        param!(offset_on_reverse: real 0.0..=3e37);
+        param!(acceleration_init_long: real 0.0..=3e37);
+        param!(acceleration_long: real 0.0..=3e37);
+        param!(acceleration_max_long: real 0.0..=3e37);
+        param!(acceleration_init_short: real 0.0..=3e37);
+        param!(acceleration_short: real 0.0..=3e37);
+        param!(acceleration_max_short: real 0.0..=3e37);
+        Ok(())
+    }
+}
+
+/// TA_SAREXT - Parabolic SAR - Extended
+///
+/// Input  = double, double
+/// Output = double
+///
+/// The optional parameters are the fields of [`SarExtOptions`]
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::sarext(&high_prices, &low_prices, &rust_ta_lib::wrapper::SarExtOptions::default()).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn sarext<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    options: &SarExtOptions,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sarext_range(high, low, options, ..)
+}
+
+/// [`sarext`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sarext_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    options: &SarExtOptions,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    options.validate()?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sarext)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                options.start_value,
+                options.offset_on_reverse,
+                options.acceleration_init_long,
+                options.acceleration_long,
+                options.acceleration_max_long,
+                options.acceleration_init_short,
+                options.acceleration_short,
+                options.acceleration_max_short,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sarext`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`sarext_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sarext_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    options: &SarExtOptions,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len()])?;
+    options.validate()?;
+
+    compute_into(
+        len,
+        || unsafe {
+            crate::TA_SAREXT_Lookback(
+                options.start_value,
+                options.offset_on_reverse,
+                options.acceleration_init_long,
+                options.acceleration_long,
+                options.acceleration_max_long,
+                options.acceleration_init_short,
+                options.acceleration_short,
+                options.acceleration_max_short,
+            )
+        },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sarext)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                options.start_value,
+                options.offset_on_reverse,
+                options.acceleration_init_long,
+                options.acceleration_long,
+                options.acceleration_max_long,
+                options.acceleration_init_short,
+                options.acceleration_short,
+                options.acceleration_max_short,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`sarext`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sarext_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    options: &SarExtOptions,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sarext(high, low, options)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sarext`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sarext_lookback(options: &SarExtOptions) -> Result<usize, TaError> {
+    options.validate()?;
+    lookback(|| unsafe {
+        crate::TA_SAREXT_Lookback(
+            options.start_value,
+            options.offset_on_reverse,
+            options.acceleration_init_long,
+            options.acceleration_long,
+            options.acceleration_max_long,
+            options.acceleration_init_short,
+            options.acceleration_short,
+            options.acceleration_max_short,
+        )
+    })
+}
+
+/// TA_SIN - Vector Trigonometric Sin
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::sin(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn sin<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sin_range(in_real, ..)
+}
+
+/// [`sin`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sin_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sin)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sin`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`sin_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sin_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_SIN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sin)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`sin`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sin_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sin(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sin`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sin_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_SIN_Lookback() })
+}
+
+/// TA_SINH - Vector Trigonometric Sinh
+///
+/// Input  = double
+/// Output = double
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::sinh(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn sinh<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sinh_range(in_real, ..)
+}
+
+/// [`sinh`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sinh_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sinh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sinh`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`sinh_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sinh_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_SINH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sinh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`sinh`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sinh_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sinh(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sinh`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sinh_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_SINH_Lookback() })
+}
+
+///
+/// TA_SMA - Simple Moving Average
 ///
+/// Input  = double
+/// Output = double
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let inReal: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs,_, begin) = rust_ta_lib::wrapper::s_mama( &close_prices,0.2,0.3);
-/// for (index, value) in outs.iter().enumerate() {
+/// let (outReal, begin) = rust_ta_lib::wrapper::sma( 10,&inReal).unwrap();
+/// for (index, value) in outReal.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_mama(
-    in_real: &Vec<f32>,
-    in_fast_limit: f64,
-    in_flow_limit: f64,
-) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut mama: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut fama: Vec<crate::TA_Real> = Vec::with_capacity(in_real.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_MAMA(
-            0,                        // index of the first close to use
-            in_real.len() as i32 - 1, // index of the last close to use
-            in_real.as_ptr(),         // pointer to the first element of the high vector
-            in_fast_limit,            // pointer to the first element of the close vector
-            in_flow_limit,            // period of the atr
-            &mut out_begin,           // set to index of the first close to have an atr value
-            &mut out_size,            // set to number of atr values computed
-            mama.as_mut_ptr(),        // pointer to the first element of the output vector
-            fama.as_mut_ptr(),        // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                mama.set_len(out_size as usize);
-                fama.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
-
-    (mama, fama, out_begin)
+pub fn sma<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+) -> Result<(Vec<crate::TA_Real>, crate::TA_Integer), TaError> {
+    sma_range(period, close_prices, ..)
 }
-pub fn mavp() {}
-pub fn s_mavp() {}
-pub fn max() {}
-pub fn s_max() {}
-pub fn maxindex() {}
-pub fn s_maxindex() {}
-pub fn medprice() {}
-pub fn s_medprice() {}
 
+/// [`sma`] restricted to the inputs whose index is in `range`
 ///
-/// TA_MFI - Money Flow Index
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sma_range<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<crate::TA_Real>, crate::TA_Integer), TaError> {
+    param!(period: int 2..=100000);
+    let ([out], out_begin) = compute(
+        close_prices.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ma)(
+                start,
+                end,
+                close_prices.as_ptr(), // pointer to the first element of the vector
+                period,                // period of the sma
+                crate::TA_MAType_TA_MAType_SMA, // type of the MA, here forced to sma
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sma`] writing into caller-provided buffers instead of allocating
 ///
-/// `Input`:  high, low, close, volume, timeperiod
+/// Each buffer needs room for the input length minus [`sma_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sma_into<T: TaFloat>(
+    period: u32,
+    close_prices: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(period: int 2..=100000);
+    compute_into(
+        close_prices.len(),
+        || unsafe { crate::TA_SMA_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ma)(
+                start,
+                end,
+                close_prices.as_ptr(), // pointer to the first element of the vector
+                period,                // period of the sma
+                crate::TA_MAType_TA_MAType_SMA, // type of the MA, here forced to sma
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`sma`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sma_aligned<T: TaFloat>(period: u32, close_prices: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sma(period, close_prices)?;
+    let len = close_prices.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sma`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sma_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    lookback(|| unsafe { crate::TA_SMA_Lookback(period) })
+}
+
+/// TA_SQRT - Vector Square Root
 ///
-/// `Output`: (1st, 2nd)
+/// Input  = double
+/// Output = double
 ///
-///    1st: output vector(f64)
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::sqrt(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn sqrt<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sqrt_range(in_real, ..)
+}
+
+/// [`sqrt`] restricted to the inputs whose index is in `range`
 ///
-///    2nd: the first index of inputs corresponding to an valid output value
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sqrt_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sqrt)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`sqrt`] writing into caller-provided buffers instead of allocating
 ///
-pub fn mfi(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-    volume: &Vec<f64>,
-    timeperiod: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) || clen.ne(&volume.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+/// Each buffer needs room for the input length minus [`sqrt_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sqrt_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    compute_into(
+        len,
+        || unsafe { crate::TA_SQRT_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sqrt)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_MFI(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            volume.as_ptr(),  // pointer to the volume vector
-            timeperiod,       // time period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`sqrt`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sqrt_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sqrt(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
-}
-pub fn s_mfi() {}
-pub fn midpoint() {}
-pub fn s_midpoint() {}
-pub fn midprice() {}
-pub fn s_midprice() {}
-pub fn min() {}
-pub fn s_min() {}
-pub fn minindex() {}
-pub fn s_minindex() {}
-pub fn minmax() {}
-pub fn s_minmax() {}
-pub fn minmaxindex() {}
-pub fn s_minmaxindex() {}
-pub fn minus_di() {}
-pub fn s_minus_di() {}
+/// Number of input values [`sqrt`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sqrt_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_SQRT_Lookback() })
+}
 
+/// TA_STDDEV - Standard Deviation
 ///
-/// TA_MINUS_DM - Minus Directional Movement
-///
-/// Input  = High, Low
+/// Input  = double
 /// Output = double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInTimePeriod:(From 1 to 100000)
+/// optInTimePeriod:(From 2 to 100000)
 ///    Number of period
 ///
+/// optInNbDev:
+///    Nb of deviations
+///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -1557,164 +15685,744 @@ pub fn s_minus_di() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::minus_dm( 10,&high_prices,&low_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::stddev(&close_prices, 5, 1.0).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn minus_dm(period: u32, high: &Vec<f64>, low: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(high.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn stddev<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    stddev_range(in_real, timeperiod, nbdev, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_MINUS_DM(
-            0,                     // index of the first close to use
-            high.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),         // pointer to the first element of the high vector
-            low.as_ptr(),          // pointer to the first element of the low vector
-            period as i32,         // pointer to the first element of the close vector
-            &mut out_begin,        // set to index of the first close to have an atr value
-            &mut out_size,         // set to number of atr values computed
-            out.as_mut_ptr(),      // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`stddev`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn stddev_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.stddev)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                nbdev,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`stddev`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`stddev_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn stddev_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_STDDEV_Lookback(timeperiod, nbdev) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.stddev)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                nbdev,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`stddev`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn stddev_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = stddev(in_real, timeperiod, nbdev)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`stddev`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn stddev_lookback(timeperiod: u32, nbdev: f64) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    lookback(|| unsafe { crate::TA_STDDEV_Lookback(timeperiod, nbdev) })
 }
+
+/// Output of [`stoch`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stoch {
+    /// Slow %K
+    pub slow_k: Vec<f64>,
+    /// Slow %D
+    pub slow_d: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
 ///
-/// TA_S_MINUS_DM - Minus Directional Movement
+/// TA_STOCH - Stochastic
 ///
-/// Input  = High, Low
-/// Output = double
+/// Input  = High, Low, Close
+/// Output = double, double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInTimePeriod:(From 1 to 100000)
-///    Number of period
+/// optInFastK_Period:(From 1 to 100000)
+///    Time period for building the Fast-K line
+///
+/// optInSlowK_Period:(From 1 to 100000)
+///    Smoothing for making the Slow-K line. Usually set to 3
+///
+/// optInSlowK_MAType:
+///    Type of Moving Average for Slow-K
+///
+/// optInSlowD_Period:(From 1 to 100000)
+///    Smoothing for making the Slow-D line
+///
+/// optInSlowD_MAType:
+///    Type of Moving Average for Slow-D
 ///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let inReal: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::s_minus_dm( 10,&high_prices,&low_prices);
-/// for (index, value) in outs.iter().enumerate() {
+/// let high = inReal.clone();
+/// let low = inReal.clone();
+/// let close = inReal.clone();
+/// let rust_ta_lib::wrapper::Stoch { slow_k: outSlowK, slow_d: outSlowD, begin } = rust_ta_lib::wrapper::stoch(9,3,rust_ta_lib::MaType::Sma,3,rust_ta_lib::MaType::Sma,&high,&low,&close).unwrap();
+/// for (index, value) in outSlowK.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///         println!("outs index {} = {:?}", begin + index as i32 + 1, outSlowD.get(index));
 ///  }
 /// ```
-pub fn s_minus_dm(period: u32, high: &Vec<f32>, low: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(high.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+#[allow(clippy::too_many_arguments)]
+pub fn stoch<T: TaFloat>(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Stoch, TaError> {
+    stoch_range(
+        fastk_period,
+        slowk_period,
+        optInSlowK_MAType,
+        slowd_period,
+        optInSlowD_MAType,
+        high,
+        low,
+        close,
+        ..,
+    )
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_MINUS_DM(
-            0,                     // index of the first close to use
-            high.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),         // pointer to the first element of the high vector
-            low.as_ptr(),          // pointer to the first element of the low vector
-            period as i32,         // pointer to the first element of the close vector
-            &mut out_begin,        // set to index of the first close to have an atr value
-            &mut out_size,         // set to number of atr values computed
-            out.as_mut_ptr(),      // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`stoch`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+#[allow(clippy::too_many_arguments)]
+pub fn stoch_range<T: TaFloat>(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<Stoch, TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(fastk_period: int 1..=100000);
+    param!(slowk_period: int 1..=100000);
+    param!(slowd_period: int 1..=100000);
+    let ([outSlowK, outSlowD], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [outSlowK, outSlowD]| unsafe {
+            (T::FFI.stoch)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastk_period,   // period of the atr
+                slowk_period,
+                optInSlowK_MAType.into(),
+                slowd_period,
+                optInSlowD_MAType.into(),
+                out_begin,
+                out_size,
+                outSlowK,
+                outSlowD,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok(Stoch {
+        slow_k: outSlowK,
+        slow_d: outSlowD,
+        begin: out_begin,
+    })
 }
-pub fn mom() {}
-pub fn s_mom() {}
-pub fn mult() {}
-pub fn s_mult() {}
 
+/// [`stoch`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_NATR - Normalized Average True Range
+/// Each buffer needs room for the input length minus [`stoch_lookback`] values. Returns the
+/// begin index and the number of values written.
+#[allow(clippy::too_many_arguments)]
+pub fn stoch_into<T: TaFloat>(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    slowk: &mut [f64],
+    slowd: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(fastk_period: int 1..=100000);
+    param!(slowk_period: int 1..=100000);
+    param!(slowd_period: int 1..=100000);
+    compute_into(
+        len,
+        || unsafe {
+            crate::TA_STOCH_Lookback(
+                fastk_period,
+                slowk_period,
+                optInSlowK_MAType.into(),
+                slowd_period,
+                optInSlowD_MAType.into(),
+            )
+        },
+        [slowk, slowd],
+        |start, end, out_begin, out_size, [outSlowK, outSlowD]| unsafe {
+            (T::FFI.stoch)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the first element of the high vector
+                low.as_ptr(),   // pointer to the first element of the low vector
+                close.as_ptr(), // pointer to the first element of the close vector
+                fastk_period,   // period of the atr
+                slowk_period,
+                optInSlowK_MAType.into(),
+                slowd_period,
+                optInSlowD_MAType.into(),
+                out_begin,
+                out_size,
+                outSlowK,
+                outSlowD,
+            )
+        },
+    )
+}
+
+/// [`stoch`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+#[allow(clippy::too_many_arguments)]
+pub fn stoch_aligned<T: TaFloat>(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Stoch, TaError> {
+    let out = stoch(
+        fastk_period,
+        slowk_period,
+        optInSlowK_MAType,
+        slowd_period,
+        optInSlowD_MAType,
+        high,
+        low,
+        close,
+    )?;
+    let len = high.len();
+    Ok(Stoch {
+        slow_k: align(out.slow_k, out.begin, len, f64::NAN),
+        slow_d: align(out.slow_d, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`stoch`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+#[allow(clippy::too_many_arguments)]
+pub fn stoch_compat<T: TaFloat>(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    compatibility: Compatibility,
+) -> Result<Stoch, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        stoch(
+            fastk_period,
+            slowk_period,
+            optInSlowK_MAType,
+            slowd_period,
+            optInSlowD_MAType,
+            high,
+            low,
+            close,
+        )
+    })
+}
+
+/// Number of input values [`stoch`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn stoch_lookback(
+    fastk_period: u32,
+    slowk_period: u32,
+    optInSlowK_MAType: MaType,
+    slowd_period: u32,
+    optInSlowD_MAType: MaType,
+) -> Result<usize, TaError> {
+    param!(fastk_period: int 1..=100000);
+    param!(slowk_period: int 1..=100000);
+    param!(slowd_period: int 1..=100000);
+    lookback(|| unsafe {
+        crate::TA_STOCH_Lookback(
+            fastk_period,
+            slowk_period,
+            optInSlowK_MAType.into(),
+            slowd_period,
+            optInSlowD_MAType.into(),
+        )
+    })
+}
+
+/// Output of [`stochf`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochF {
+    /// Fast %K
+    pub fast_k: Vec<f64>,
+    /// Fast %D
+    pub fast_d: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+/// TA_STOCHF - Stochastic Fast
 ///
-/// `Input`:  high, low, close, timeperiod
+/// Input  = double, double, double
+/// Output = double, double
 ///
-/// `Output`: (1st, 2nd)
+/// Optional Parameters
+/// -------------------
+/// optInFastK_Period:(From 1 to 100000)
+///    Time period for building the Fast-K line
 ///
-///    1st: output vector(f64)
+/// optInFastD_Period:(From 1 to 100000)
+///    Smoothing for making the Fast-D line. Usually set to 3
 ///
-///    2nd: the first index of inputs corresponding to an valid output value
+/// optInFastD_MAType:
+///    Type of Moving Average for Fast-D
+///
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let rust_ta_lib::wrapper::StochF { fast_k: fastk, fast_d: fastd, begin } = rust_ta_lib::wrapper::stochf(&high_prices, &low_prices, &close_prices, 5, 3, rust_ta_lib::MaType::Sma).unwrap();
+/// for (index, value) in fastk.iter().enumerate() {
+///        println!("fastk index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn stochf<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+) -> Result<StochF, TaError> {
+    stochf_range(
+        high,
+        low,
+        close,
+        fastk_period,
+        fastd_period,
+        fastd_matype,
+        ..,
+    )
+}
+
+/// [`stochf`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn stochf_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<StochF, TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
+    let ([fastk, fastd], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [fastk, fastd]| unsafe {
+            (T::FFI.stochf)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                fastk_period,
+                fastd_period,
+                fastd_matype.into(),
+                out_begin,
+                out_size,
+                fastk,
+                fastd,
+            )
+        },
+    )?;
+
+    Ok(StochF {
+        fast_k: fastk,
+        fast_d: fastd,
+        begin: out_begin,
+    })
+}
+
+/// [`stochf`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`stochf_lookback`] values. Returns the
+/// begin index and the number of values written.
+#[allow(clippy::too_many_arguments)]
+pub fn stochf_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+    fastk: &mut [f64],
+    fastd: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_STOCHF_Lookback(fastk_period, fastd_period, fastd_matype.into()) },
+        [fastk, fastd],
+        |start, end, out_begin, out_size, [fastk, fastd]| unsafe {
+            (T::FFI.stochf)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                fastk_period,
+                fastd_period,
+                fastd_matype.into(),
+                out_begin,
+                out_size,
+                fastk,
+                fastd,
+            )
+        },
+    )
+}
+
+/// [`stochf`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn stochf_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+) -> Result<StochF, TaError> {
+    let out = stochf(high, low, close, fastk_period, fastd_period, fastd_matype)?;
+    let len = high.len();
+    Ok(StochF {
+        fast_k: align(out.fast_k, out.begin, len, f64::NAN),
+        fast_d: align(out.fast_d, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`stochf`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn stochf_compat<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+    compatibility: Compatibility,
+) -> Result<StochF, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        stochf(high, low, close, fastk_period, fastd_period, fastd_matype)
+    })
+}
+
+/// Number of input values [`stochf`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn stochf_lookback(
+    fastk_period: u32,
+    fastd_period: u32,
+    fastd_matype: MaType,
+) -> Result<usize, TaError> {
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
+    lookback(|| unsafe {
+        crate::TA_STOCHF_Lookback(fastk_period, fastd_period, fastd_matype.into())
+    })
+}
+
+/// Output of [`stochrsi`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochRsi {
+    /// Fast %K of the RSI
+    pub fast_k: Vec<f64>,
+    /// Fast %D of the RSI
+    pub fast_d: Vec<f64>,
+    /// Index of the input the first computed value belongs to
+    pub begin: crate::TA_Integer,
+}
+
+///
+/// TA_STOCHRSI - Stochastic Relative Strength Index
+///
+/// `Input`:  close, timeperiod, fastk_period, fastd_period, fastd_matype=0
+///
+/// `Output`: (1st, 2nd, 3rd)
+///
+///    1st: output fastk vector(f64)
+///
+///    2nd: output fastd vector(f64)
+///
+///    3rd: the first index of inputs corresponding to an valid output value
+///
+pub fn stochrsi<T: TaFloat>(
+    close: &[T],
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+) -> Result<StochRsi, TaError> {
+    stochrsi_range(
+        close,
+        timeperiod,
+        fastk_period,
+        fastd_period,
+        fastd_matype,
+        ..,
+    )
+}
+
+/// [`stochrsi`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn stochrsi_range<T: TaFloat>(
+    close: &[T],
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+    range: impl RangeBounds<usize>,
+) -> Result<StochRsi, TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
+    let clen = close.len();
+
+    let ([out_fastk, out_fastd], out_begin) = compute(
+        clen,
+        range,
+        |start, end, out_begin, out_size, [out_fastk, out_fastd]| unsafe {
+            (T::FFI.stochrsi)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                fastk_period,   // fastk period
+                fastd_period,   // slowd period
+                fastd_matype.into(),
+                out_begin,
+                out_size,
+                out_fastk,
+                out_fastd,
+            )
+        },
+    )?;
+
+    Ok(StochRsi {
+        fast_k: out_fastk,
+        fast_d: out_fastd,
+        begin: out_begin,
+    })
+}
+
+/// [`stochrsi`] writing into caller-provided buffers instead of allocating
 ///
-pub fn natr(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
+/// Each buffer needs room for the input length minus [`stochrsi_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn stochrsi_into<T: TaFloat>(
+    close: &[T],
     timeperiod: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+    fastk: &mut [f64],
+    fastd: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
     let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    compute_into(
+        clen,
+        || unsafe {
+            crate::TA_STOCHRSI_Lookback(timeperiod, fastk_period, fastd_period, fastd_matype.into())
+        },
+        [fastk, fastd],
+        |start, end, out_begin, out_size, [out_fastk, out_fastd]| unsafe {
+            (T::FFI.stochrsi)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                fastk_period,   // fastk period
+                fastd_period,   // slowd period
+                fastd_matype.into(),
+                out_begin,
+                out_size,
+                out_fastk,
+                out_fastd,
+            )
+        },
+    )
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_NATR(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            timeperiod,       // time period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`stochrsi`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn stochrsi_aligned<T: TaFloat>(
+    close: &[T],
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+) -> Result<StochRsi, TaError> {
+    let out = stochrsi(close, timeperiod, fastk_period, fastd_period, fastd_matype)?;
+    let len = close.len();
+    Ok(StochRsi {
+        fast_k: align(out.fast_k, out.begin, len, f64::NAN),
+        fast_d: align(out.fast_d, out.begin, len, f64::NAN),
+        begin: out.begin,
+    })
+}
+
+/// [`stochrsi`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn stochrsi_compat<T: TaFloat>(
+    close: &[T],
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+    compatibility: Compatibility,
+) -> Result<StochRsi, TaError> {
+    crate::talib::with_compatibility(compatibility, || {
+        stochrsi(close, timeperiod, fastk_period, fastd_period, fastd_matype)
+    })
+}
 
-    (out, out_begin)
+/// Number of input values [`stochrsi`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn stochrsi_lookback(
+    timeperiod: i32,
+    fastk_period: i32,
+    fastd_period: i32,
+    fastd_matype: MaType,
+) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(fastk_period: int 1..=100000);
+    param!(fastd_period: int 1..=100000);
+    lookback(|| unsafe {
+        crate::TA_STOCHRSI_Lookback(timeperiod, fastk_period, fastd_period, fastd_matype.into())
+    })
 }
-pub fn s_natr() {}
-///
-/// TA_COS - On Balance Volums
+
+/// TA_SUB - Vector Arithmetic Substraction
 ///
-/// Input  = double
+/// Input  = double, double
 /// Output = double
+///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -1722,105 +16430,208 @@ pub fn s_natr() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let volumes = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::obv(&close_prices,&volumes);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let in_real1: Vec<f64> = close_prices.iter().map(|c| c * 1.5 + 0.0002).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::sub(&close_prices, &in_real1).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn obv(close: &Vec<f64>, volume: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn sub<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sub_range(in_real0, in_real1, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_OBV(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            volume.as_ptr(),
-            &mut out_begin,   // set to index of the first close to have an atr value
-            &mut out_size,    // set to number of atr values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`sub`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sub_range<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sub)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`sub`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_COS - On Balance Volume
+/// Each buffer needs room for the input length minus [`sub_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sub_into<T: TaFloat>(
+    in_real0: &[T],
+    in_real1: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[in_real0.len(), in_real1.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_SUB_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sub)(
+                start,
+                end,
+                in_real0.as_ptr(),
+                in_real1.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`sub`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sub_aligned<T: TaFloat>(in_real0: &[T], in_real1: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sub(in_real0, in_real1)?;
+    let len = in_real0.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sub`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sub_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_SUB_Lookback() })
+}
+
+/// TA_SUM - Summation
 ///
 /// Input  = double
 /// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let volumes = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::s_obv(&close_prices,&volumes);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::sum(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_obv(close: &Vec<f32>, volume: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn sum<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    sum_range(in_real, timeperiod, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_S_OBV(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            volume.as_ptr(),
-            &mut out_begin,   // set to index of the first close to have an atr value
-            &mut out_size,    // set to number of atr values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`sum`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn sum_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sum)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
-pub fn plus_di() {}
-pub fn s_plus_di() {}
+
+/// [`sum`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_PLUS_DM - Plus Directional Movement
+/// Each buffer needs room for the input length minus [`sum_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn sum_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_SUM_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.sum)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`sum`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn sum_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = sum(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`sum`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn sum_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_SUM_Lookback(timeperiod) })
+}
+
+/// TA_T3 - Triple Exponential Moving Average (T3)
 ///
-/// Input  = High, Low
+/// Input  = double
 /// Output = double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInTimePeriod:(From 1 to 100000)
+/// optInTimePeriod:(From 2 to 100000)
 ///    Number of period
 ///
+/// optInVFactor:(From 0.0 to 1.0)
+///    Volume Factor
+///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -1828,162 +16639,196 @@ pub fn s_plus_di() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::plus_dm( 10,&high_prices,&low_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::t3(&close_prices, 5, 0.7).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn plus_dm(period: u32, high: &Vec<f64>, low: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(high.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn t3<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    vfactor: f64,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    t3_range(in_real, timeperiod, vfactor, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_PLUS_DM(
-            0,                     // index of the first close to use
-            high.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),         // pointer to the first element of the high vector
-            low.as_ptr(),          // pointer to the first element of the low vector
-            period as i32,         // pointer to the first element of the close vector
-            &mut out_begin,        // set to index of the first close to have an atr value
-            &mut out_size,         // set to number of atr values computed
-            out.as_mut_ptr(),      // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`t3`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn t3_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    vfactor: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(vfactor: real 0.0..=1.0);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.t3)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                vfactor,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`t3`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_PLUS_DM - Plus Directional Movement
+/// Each buffer needs room for the input length minus [`t3_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn t3_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    vfactor: f64,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(vfactor: real 0.0..=1.0);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_T3_Lookback(timeperiod, vfactor) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.t3)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                vfactor,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`t3`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn t3_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    vfactor: f64,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = t3(in_real, timeperiod, vfactor)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`t3`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn t3_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    vfactor: f64,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || t3(in_real, timeperiod, vfactor))
+}
+
+/// Number of input values [`t3`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn t3_lookback(timeperiod: u32, vfactor: f64) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    param!(vfactor: real 0.0..=1.0);
+    lookback(|| unsafe { crate::TA_T3_Lookback(timeperiod, vfactor) })
+}
+
+/// TA_TAN - Vector Trigonometric Tan
 ///
-/// Input  = High, Low
+/// Input  = double
 /// Output = double
 ///
-/// Optional Parameters
-/// -------------------
-/// optInTimePeriod:(From 1 to 100000)
-///    Number of period
-///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high_prices = close_prices.clone();
-/// let low_prices = close_prices.clone();
-/// let (outs, begin) = rust_ta_lib::wrapper::s_plus_dm( 10,&high_prices,&low_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::tan(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_plus_dm(period: u32, high: &Vec<f32>, low: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(high.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn tan<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    tan_range(in_real, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_PLUS_DM(
-            0,                     // index of the first close to use
-            high.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),         // pointer to the first element of the high vector
-            low.as_ptr(),          // pointer to the first element of the low vector
-            period as i32,         // pointer to the first element of the close vector
-            &mut out_begin,        // set to index of the first close to have an atr value
-            &mut out_size,         // set to number of atr values computed
-            out.as_mut_ptr(),      // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`tan`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn tan_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tan)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`tan`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_PPO - Percentage Price Oscillator
-///
-/// `Input`  = close, fastperiod(12), slowperiod(26), matype
-///
-/// `Output`: (1st, 2nd)
-///
-///    1st: output vector(f64)
-///
-///    2nd: the first index of inputs corresponding to an valid output value
-///
-pub fn ppo(
-    close: &Vec<f64>,
-    fastperiod: i32,
-    slowperiod: i32,
-    matype: crate::TA_MAType,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// Each buffer needs room for the input length minus [`tan_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn tan_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_PPO(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fastperiod,             // fast period (suggest default 12)
-            slowperiod,             // slow period (suggest default 26)
-            matype,                 // MA Type
-            &mut out_begin,         // set to index of the first close to have an valid output value
-            &mut out_size,          // set to number of values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    compute_into(
+        len,
+        || unsafe { crate::TA_TAN_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tan)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
 
-    (out, out_begin)
+/// [`tan`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn tan_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = tan(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
 }
-pub fn s_ppo() {}
-pub fn roc() {}
-pub fn s_roc() {}
-pub fn rocp() {}
-pub fn s_rocp() {}
-pub fn rocr() {}
-pub fn s_rocr() {}
-pub fn rocr100() {}
-pub fn s_rocr100() {}
-///
-/// TA_RSI - Relative Strength Index
+
+/// Number of input values [`tan`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn tan_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_TAN_Lookback() })
+}
+
+/// TA_TANH - Vector Trigonometric Tanh
 ///
 /// Input  = double
 /// Output = double
+///
 /// #Sample
 /// ```
 /// let close_prices: Vec<f64> = vec![
@@ -1991,453 +16836,714 @@ pub fn s_rocr100() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::rsi(10,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::tanh(&close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn rsi(
-    period: u32,
-    close_prices: &Vec<crate::TA_Real>,
-) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
-    let mut out: Vec<crate::TA_Real> = Vec::with_capacity(close_prices.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_RSI(
-            0,                             // index of the first close to use
-            close_prices.len() as i32 - 1, // index of the last close to use
-            close_prices.as_ptr(),         // pointer to the first element of the vector
-            period as i32,                 // period of the rsi
-            &mut out_begin,                // set to index of the first close to have an rsi value
-            &mut out_size,                 // set to number of sma values computed
-            out.as_mut_ptr(),              // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_RSI call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+pub fn tanh<T: TaFloat>(in_real: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    tanh_range(in_real, ..)
+}
 
-    (out, out_begin)
+/// [`tanh`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn tanh_range<T: TaFloat>(
+    in_real: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tanh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )?;
+
+    Ok((out, out_begin))
 }
+
+/// [`tanh`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_RSI - Relative Strength Index
+/// Each buffer needs room for the input length minus [`tanh_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn tanh_into<T: TaFloat>(
+    in_real: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TANH_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tanh)(start, end, in_real.as_ptr(), out_begin, out_size, out)
+        },
+    )
+}
+
+/// [`tanh`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn tanh_aligned<T: TaFloat>(in_real: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = tanh(in_real)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`tanh`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn tanh_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_TANH_Lookback() })
+}
+
+/// TA_TEMA - Triple Exponential Moving Average
 ///
-/// Input  = float
+/// Input  = double
 /// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
 /// #Sample
 /// ```
-/// let close_prices: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::s_rsi(10,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::tema(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_rsi(period: u32, close_prices: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close_prices.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_RSI(
-            0,                             // index of the first close to use
-            close_prices.len() as i32 - 1, // index of the last close to use
-            close_prices.as_ptr(),         // pointer to the first element of the vector
-            period as i32,                 // period of the rsi
-            &mut out_begin,                // set to index of the first close to have an rsi value
-            &mut out_size,                 // set to number of sma values computed
-            out.as_mut_ptr(),              // pointer to the first element of the output vector
-        );
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_RSI call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+pub fn tema<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    tema_range(in_real, timeperiod, ..)
+}
+
+/// [`tema`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn tema_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
-pub fn sar() {}
-pub fn s_sar() {}
-pub fn sarext() {}
-pub fn s_sarext() {}
-pub fn sin() {}
-pub fn s_sin() {}
-pub fn sinh() {}
-pub fn s_sinh() {}
+
+/// [`tema`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_SMA - Simple Moving Average
+/// Each buffer needs room for the input length minus [`tema_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn tema_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TEMA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tema)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`tema`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn tema_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = tema(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`tema`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn tema_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || tema(in_real, timeperiod))
+}
+
+/// Number of input values [`tema`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn tema_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_TEMA_Lookback(timeperiod) })
+}
+
+/// TA_TRANGE - True Range
 ///
-/// Input  = double
+/// Input  = double, double, double
 /// Output = double
+///
 /// #Sample
 /// ```
-/// let inReal: Vec<f64> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outReal, begin) = rust_ta_lib::wrapper::sma( 10,&inReal);
-/// for (index, value) in outReal.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::trange(&high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn sma(
-    period: u32,
-    close_prices: &Vec<crate::TA_Real>,
-) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
-    let mut out: Vec<crate::TA_Real> = Vec::with_capacity(close_prices.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_MA(
-            0,                              // index of the first close to use
-            close_prices.len() as i32 - 1,  // index of the last close to use
-            close_prices.as_ptr(),          // pointer to the first element of the vector
-            period as i32,                  // period of the sma
-            crate::TA_MAType_TA_MAType_SMA, // type of the MA, here forced to sma
-            &mut out_begin,                 // set to index of the first close to have an sma value
-            &mut out_size,                  // set to number of sma values computed
-            out.as_mut_ptr(),               // pointer to the first element of the output vector
-        );
-        match ret_code {
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+pub fn trange<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    trange_range(high, low, close, ..)
+}
 
-    (out, out_begin)
+/// [`trange`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn trange_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trange)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
 }
 
+/// [`trange`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_SMA - Simple Moving Average
+/// Each buffer needs room for the input length minus [`trange_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn trange_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TRANGE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trange)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`trange`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn trange_aligned<T: TaFloat>(high: &[T], low: &[T], close: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = trange(high, low, close)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`trange`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn trange_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_TRANGE_Lookback() })
+}
+
+/// TA_TRIMA - Triangular Moving Average
 ///
-/// Input  = float
+/// Input  = double
 /// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
+///
 /// #Sample
 /// ```
-/// let inReal: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outReal, begin) = rust_ta_lib::wrapper::s_sma( 10,&inReal);
-/// for (index, value) in outReal.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+/// let (out, begin) = rust_ta_lib::wrapper::trima(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_sma(period: u32, close_prices: &Vec<f32>) -> (Vec<crate::TA_Real>, crate::TA_Integer) {
-    let mut out: Vec<crate::TA_Real> = Vec::with_capacity(close_prices.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_MA(
-            0,                              // index of the first close to use
-            close_prices.len() as i32 - 1,  // index of the last close to use
-            close_prices.as_ptr(),          // pointer to the first element of the vector
-            period as i32,                  // period of the sma
-            crate::TA_MAType_TA_MAType_SMA, // type of the MA, here forced to sma
-            &mut out_begin,                 // set to index of the first close to have an sma value
-            &mut out_size,                  // set to number of sma values computed
-            out.as_mut_ptr(),               // pointer to the first element of the output vector
-        );
-        match ret_code {
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
-    (out, out_begin)
+pub fn trima<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    trima_range(in_real, timeperiod, ..)
 }
-pub fn sqrt() {}
-pub fn s_sqrt() {}
-pub fn stddev() {}
-pub fn s_stddev() {}
-///
-/// TA_STOCH - Stochastic
-///
-/// Input  = High, Low, Close
-/// Output = double, double
-///
-/// Optional Parameters
-/// -------------------
-/// optInFastK_Period:(From 1 to 100000)
-///    Time period for building the Fast-K line
-///
-/// optInSlowK_Period:(From 1 to 100000)
-///    Smoothing for making the Slow-K line. Usually set to 3
+
+/// [`trima`] restricted to the inputs whose index is in `range`
 ///
-/// optInSlowK_MAType:
-///    Type of Moving Average for Slow-K
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn trima_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trima)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`trima`] writing into caller-provided buffers instead of allocating
 ///
-/// optInSlowD_Period:(From 1 to 100000)
-///    Smoothing for making the Slow-D line
+/// Each buffer needs room for the input length minus [`trima_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn trima_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TRIMA_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trima)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`trima`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn trima_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = trima(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`trima`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn trima_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_TRIMA_Lookback(timeperiod) })
+}
+
+/// TA_TRIX - 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA
 ///
-/// optInSlowD_MAType:
-///    Type of Moving Average for Slow-D
+/// Input  = double
+/// Output = double
+///
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
 ///
 /// #Sample
 /// ```
-/// let inReal: Vec<f64> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high = inReal.clone();
-/// let low = inReal.clone();
-/// let close = inReal.clone();
-/// let (outSlowK,outSlowD, begin) = rust_ta_lib::wrapper::stoch(9,3,0,3,0,&high,&low,&close);
-/// for (index, value) in outSlowK.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
-///         println!("outs index {} = {:?}", begin + index as i32 + 1, outSlowD.get(index));
+/// let (out, begin) = rust_ta_lib::wrapper::trix(&close_prices, 30).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-#[allow(clippy::too_many_arguments)]
-pub fn stoch(
-    fastk_period: u32,
-    slowk_period: u32,
-    optInSlowK_MAType: crate::TA_MAType,
-    slowd_period: u32,
-    optInSlowD_MAType: crate::TA_MAType,
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
-) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut outSlowK: Vec<f64> = Vec::with_capacity(close.len());
-    let mut outSlowD: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn trix<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    trix_range(in_real, timeperiod, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_STOCH(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fastk_period as i32,    // period of the atr
-            slowk_period as i32,
-            optInSlowK_MAType,
-            slowd_period as i32,
-            optInSlowD_MAType,
-            &mut out_begin, // set to index of the first close to have an atr value
-            &mut out_size,  // set to number of atr values computed
-            outSlowK.as_mut_ptr(), // pointer to the first element of the output vector
-            outSlowD.as_mut_ptr(),
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                outSlowK.set_len(out_size as usize);
-                outSlowD.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`trix`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn trix_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trix)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (outSlowK, outSlowD, out_begin)
+    Ok((out, out_begin))
 }
+
+/// [`trix`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_STOCH - Stochastic
+/// Each buffer needs room for the input length minus [`trix_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn trix_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TRIX_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.trix)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`trix`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn trix_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = trix(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// [`trix`] computed in the `compatibility` mode instead of the global [`Compatibility`] one.
+/// Other computations wait until this one is done, since the mode is global to the C library
+pub fn trix_compat<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    compatibility: Compatibility,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    crate::talib::with_compatibility(compatibility, || trix(in_real, timeperiod))
+}
+
+/// Number of input values [`trix`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn trix_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    lookback(|| unsafe { crate::TA_TRIX_Lookback(timeperiod) })
+}
+
+/// TA_TSF - Time Series Forecast
 ///
-/// Input  = High, Low, Close
-/// Output = double, double
+/// Input  = double
+/// Output = double
 ///
 /// Optional Parameters
 /// -------------------
-/// optInFastK_Period:(From 1 to 100000)
-///    Time period for building the Fast-K line
-///
-/// optInSlowK_Period:(From 1 to 100000)
-///    Smoothing for making the Slow-K line. Usually set to 3
-///
-/// optInSlowK_MAType:
-///    Type of Moving Average for Slow-K
-///
-/// optInSlowD_Period:(From 1 to 100000)
-///    Smoothing for making the Slow-D line
-///
-/// optInSlowD_MAType:
-///    Type of Moving Average for Slow-D
+/// optInTimePeriod:(From 2 to 100000)
+///    Number of period
 ///
 /// #Sample
 /// ```
-/// let inReal: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high = inReal.clone();
-/// let low = inReal.clone();
-/// let close = inReal.clone();
-/// let (outSlowK,outSlowD, begin) = rust_ta_lib::wrapper::s_stoch(9,3,0,3,0,&high,&low,&close);
-/// for (index, value) in outSlowK.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
-///         println!("outs index {} = {:?}", begin + index as i32 + 1, outSlowD.get(index));
+/// let (out, begin) = rust_ta_lib::wrapper::tsf(&close_prices, 14).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-#[allow(clippy::too_many_arguments)]
-pub fn s_stoch(
-    fastk_period: u32,
-    slowk_period: u32,
-    optInSlowK_MAType: crate::TA_MAType,
-    slowd_period: u32,
-    optInSlowD_MAType: crate::TA_MAType,
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let mut outSlowK: Vec<f64> = Vec::with_capacity(close.len());
-    let mut outSlowD: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn tsf<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    tsf_range(in_real, timeperiod, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_STOCH(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            high.as_ptr(),          // pointer to the first element of the high vector
-            low.as_ptr(),           // pointer to the first element of the low vector
-            close.as_ptr(),         // pointer to the first element of the close vector
-            fastk_period as i32,    // period of the atr
-            slowk_period as i32,
-            optInSlowK_MAType,
-            slowd_period as i32,
-            optInSlowD_MAType,
-            &mut out_begin, // set to index of the first close to have an atr value
-            &mut out_size,  // set to number of atr values computed
-            outSlowK.as_mut_ptr(), // pointer to the first element of the output vector
-            outSlowD.as_mut_ptr(),
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                outSlowK.set_len(out_size as usize);
-                outSlowD.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`tsf`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn tsf_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tsf)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (outSlowK, outSlowD, out_begin)
+    Ok((out, out_begin))
 }
 
-pub fn stochf() {}
-pub fn s_stochf() {}
-
-///
-/// TA_STOCHRSI - Stochastic Relative Strength Index
-///
-/// `Input`:  close, timeperiod, fastk_period, fastd_period, fastd_matype=0
+/// [`tsf`] writing into caller-provided buffers instead of allocating
 ///
-/// `Output`: (1st, 2nd, 3rd)
+/// Each buffer needs room for the input length minus [`tsf_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn tsf_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 2..=100000);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_TSF_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.tsf)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`tsf`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn tsf_aligned<T: TaFloat>(in_real: &[T], timeperiod: u32) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = tsf(in_real, timeperiod)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`tsf`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn tsf_lookback(timeperiod: u32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_TSF_Lookback(timeperiod) })
+}
+
+/// TA_TYPPRICE - Typical Price
 ///
-///    1st: output fastk vector(f64)
+/// Input  = double, double, double
+/// Output = double
 ///
-///    2nd: output fastd vector(f64)
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::typprice(&high_prices, &low_prices, &close_prices).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn typprice<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    typprice_range(high, low, close, ..)
+}
+
+/// [`typprice`] restricted to the inputs whose index is in `range`
 ///
-///    3rd: the first index of inputs corresponding to an valid output value
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn typprice_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.typprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`typprice`] writing into caller-provided buffers instead of allocating
 ///
-pub fn stochrsi(
-    close: &Vec<f64>,
-    timeperiod: i32,
-    fastk_period: i32,
-    fastd_period: i32,
-    fastd_matype: crate::TA_MAType,
-) -> (Vec<f64>, Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
+/// Each buffer needs room for the input length minus [`typprice_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn typprice_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
 
-    let mut out_fastk: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_fastd: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+    compute_into(
+        len,
+        || unsafe { crate::TA_TYPPRICE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.typprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_STOCHRSI(
-            0,               // the first index of the input vector to use
-            clen as i32 - 1, // the last index of the input vector to use
-            close.as_ptr(),  // pointer to the close vector
-            timeperiod,      // time period
-            fastk_period,    // fastk period
-            fastd_period,    // slowd period
-            fastd_matype,
-            &mut out_begin, // set to index of the first close to have an valid output value
-            &mut out_size,  // set to number of values computed
-            out_fastk.as_mut_ptr(), // pointer to the first element of the output fastk vector
-            out_fastd.as_mut_ptr(), // pointer to the first element of the output fastd vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                out_fastk.set_len(out_size as usize);
-                out_fastd.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`typprice`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn typprice_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = typprice(high, low, close)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out_fastk, out_fastd, out_begin)
-}
-
-pub fn s_stochrsi() {}
-pub fn sub() {}
-pub fn s_sub() {}
-pub fn sum() {}
-pub fn s_sum() {}
-pub fn t3() {}
-pub fn s_t3() {}
-pub fn tan() {}
-pub fn s_tan() {}
-pub fn tanh() {}
-pub fn s_tanh() {}
-pub fn tema() {}
-pub fn s_tema() {}
-pub fn trange() {}
-pub fn s_trange() {}
-pub fn trima() {}
-pub fn s_trima() {}
-pub fn trix() {}
-pub fn s_trix() {}
-pub fn tsf() {}
-pub fn s_tsf() {}
-pub fn typprice() {}
-pub fn s_typprice() {}
+/// Number of input values [`typprice`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn typprice_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_TYPPRICE_Lookback() })
+}
 
 ///
 /// TA_ULTOSC - Ultimate Oscillator
@@ -2460,138 +17566,356 @@ pub fn s_typprice() {}
 /// let high = inReal.clone();
 /// let low = inReal.clone();
 /// let close = inReal.clone();
-/// let (out, begin) = rust_ta_lib::wrapper::ultosc(&high,&low,&close,7,14,28);
+/// let (out, begin) = rust_ta_lib::wrapper::ultosc(&high,&low,&close,7,14,28).unwrap();
 /// for (index, value) in out.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn ultosc(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
+pub fn ultosc<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
     timeperiod1: i32,
     timeperiod2: i32,
     timeperiod3: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    ultosc_range(high, low, close, timeperiod1, timeperiod2, timeperiod3, ..)
+}
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// [`ultosc`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn ultosc_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod1: int 1..=100000);
+    param!(timeperiod2: int 1..=100000);
+    param!(timeperiod3: int 1..=100000);
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_ULTOSC(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            timeperiod1,      // time period1
-            timeperiod2,      // time period2
-            timeperiod3,      // time period3
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                out.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ultosc)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod1,    // time period1
+                timeperiod2,    // time period2
+                timeperiod3,    // time period3
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
 }
 
+/// [`ultosc`] writing into caller-provided buffers instead of allocating
 ///
-/// TA_S_ULTOSC - Ultimate Oscillator
+/// Each buffer needs room for the input length minus [`ultosc_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn ultosc_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod1: int 1..=100000);
+    param!(timeperiod2: int 1..=100000);
+    param!(timeperiod3: int 1..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_ULTOSC_Lookback(timeperiod1, timeperiod2, timeperiod3) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.ultosc)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod1,    // time period1
+                timeperiod2,    // time period2
+                timeperiod3,    // time period3
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`ultosc`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn ultosc_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = ultosc(high, low, close, timeperiod1, timeperiod2, timeperiod3)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`ultosc`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn ultosc_lookback(
+    timeperiod1: i32,
+    timeperiod2: i32,
+    timeperiod3: i32,
+) -> Result<usize, TaError> {
+    param!(timeperiod1: int 1..=100000);
+    param!(timeperiod2: int 1..=100000);
+    param!(timeperiod3: int 1..=100000);
+    lookback(|| unsafe { crate::TA_ULTOSC_Lookback(timeperiod1, timeperiod2, timeperiod3) })
+}
+
+/// TA_VAR - Variance
 ///
-/// `Input`:  high, low, close, timeperiod1, timeperiod2, timeperiod3
+/// Input  = double
+/// Output = double
 ///
-/// `Output`: (1st, 2nd)
+/// Optional Parameters
+/// -------------------
+/// optInTimePeriod:(From 1 to 100000)
+///    Number of period
 ///
-///    1st: output vector(f32)
+/// optInNbDev:
+///    Nb of deviations
 ///
-///    2nd: the first index of inputs corresponding to an valid output value
+/// #Sample
+/// ```
+/// let close_prices: Vec<f64> = vec![
+///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
+///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
+///        1.086670, 1.086630,
+/// ];
+/// let (out, begin) = rust_ta_lib::wrapper::var(&close_prices, 5, 1.0).unwrap();
+/// for (index, value) in out.iter().enumerate() {
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
+///  }
+/// ```
+pub fn var<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    var_range(in_real, timeperiod, nbdev, ..)
+}
+
+/// [`var`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn var_range<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(timeperiod: int 1..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    let len = in_real.len();
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.var)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                nbdev,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`var`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`var_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn var_into<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(timeperiod: int 1..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    let len = in_real.len();
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_VAR_Lookback(timeperiod, nbdev) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.var)(
+                start,
+                end,
+                in_real.as_ptr(),
+                timeperiod,
+                nbdev,
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`var`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn var_aligned<T: TaFloat>(
+    in_real: &[T],
+    timeperiod: u32,
+    nbdev: f64,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = var(in_real, timeperiod, nbdev)?;
+    let len = in_real.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
+
+/// Number of input values [`var`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn var_lookback(timeperiod: u32, nbdev: f64) -> Result<usize, TaError> {
+    param!(timeperiod: int 1..=100000);
+    param!(nbdev: real -3e37..=3e37);
+    lookback(|| unsafe { crate::TA_VAR_Lookback(timeperiod, nbdev) })
+}
+
+/// TA_WCLPRICE - Weighted Close Price
+///
+/// Input  = double, double, double
+/// Output = double
 ///
 /// #Sample
 /// ```
-/// let inReal: Vec<f32> = vec![
+/// let close_prices: Vec<f64> = vec![
 ///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let high = inReal.clone();
-/// let low = inReal.clone();
-/// let close = inReal.clone();
-/// let (out, begin) = rust_ta_lib::wrapper::s_ultosc(&high,&low,&close,7,14,28);
+/// let high_prices: Vec<f64> = close_prices.iter().map(|c| c + 0.0003).collect();
+/// let low_prices: Vec<f64> = close_prices.iter().map(|c| c - 0.0004).collect();
+/// let (out, begin) = rust_ta_lib::wrapper::wclprice(&high_prices, &low_prices, &close_prices).unwrap();
 /// for (index, value) in out.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
+///        println!("out index {} = {:?}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn s_ultosc(
-    high: &Vec<f32>,
-    low: &Vec<f32>,
-    close: &Vec<f32>,
-    timeperiod1: i32,
-    timeperiod2: i32,
-    timeperiod3: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+pub fn wclprice<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    wclprice_range(high, low, close, ..)
+}
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// [`wclprice`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn wclprice_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.wclprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_S_ULTOSC(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            timeperiod1,      // time period1
-            timeperiod2,      // time period2
-            timeperiod3,      // time period3
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => {
-                out.set_len(out_size as usize);
-            }
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    Ok((out, out_begin))
+}
+
+/// [`wclprice`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`wclprice_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn wclprice_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_WCLPRICE_Lookback() },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.wclprice)(
+                start,
+                end,
+                high.as_ptr(),
+                low.as_ptr(),
+                close.as_ptr(),
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`wclprice`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn wclprice_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = wclprice(high, low, close)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`wclprice`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn wclprice_lookback() -> Result<usize, TaError> {
+    lookback(|| unsafe { crate::TA_WCLPRICE_Lookback() })
 }
-pub fn var() {}
-pub fn s_var() {}
-pub fn wclprice() {}
-pub fn s_wclprice() {}
 
 ///
 /// TA_WILLR - Williams' %R
@@ -2604,49 +17928,104 @@ pub fn s_wclprice() {}
 ///
 ///    2nd: the first index of inputs corresponding to an valid output value
 ///
-pub fn willr(
-    high: &Vec<f64>,
-    low: &Vec<f64>,
-    close: &Vec<f64>,
+pub fn willr<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
     timeperiod: i32,
-) -> (Vec<f64>, crate::TA_Integer) {
-    let clen = close.len();
-    if clen.ne(&high.len()) || clen.ne(&low.len()) {
-        panic!("The length of input vectors are not equal, please double check the size of each.");
-    }
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    willr_range(high, low, close, timeperiod, ..)
+}
 
-    let mut out: Vec<f64> = Vec::with_capacity(clen);
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+/// [`willr`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn willr_range<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
 
-    unsafe {
-        crate::TA_Initialize();
-        let ret_code = crate::TA_WILLR(
-            0,                // the first index of the input vector to use
-            clen as i32 - 1,  // the last index of the input vector to use
-            high.as_ptr(),    // pointer to the high vector
-            low.as_ptr(),     // pointer to the low vector
-            close.as_ptr(),   // pointer to the close vector
-            timeperiod,       // time period
-            &mut out_begin,   // set to index of the first close to have an valid output value
-            &mut out_size,    // set to number of values computed
-            out.as_mut_ptr(), // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+    let ([out], out_begin) = compute(
+        len,
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.willr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
+
+    Ok((out, out_begin))
+}
+
+/// [`willr`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`willr_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn willr_into<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    let len = input_len(&[high.len(), low.len(), close.len()])?;
+    param!(timeperiod: int 2..=100000);
+
+    compute_into(
+        len,
+        || unsafe { crate::TA_WILLR_Lookback(timeperiod) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.willr)(
+                start,
+                end,
+                high.as_ptr(),  // pointer to the high vector
+                low.as_ptr(),   // pointer to the low vector
+                close.as_ptr(), // pointer to the close vector
+                timeperiod,     // time period
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
+}
+
+/// [`willr`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn willr_aligned<T: TaFloat>(
+    high: &[T],
+    low: &[T],
+    close: &[T],
+    timeperiod: i32,
+) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = willr(high, low, close, timeperiod)?;
+    let len = high.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`willr`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn willr_lookback(timeperiod: i32) -> Result<usize, TaError> {
+    param!(timeperiod: int 2..=100000);
+    lookback(|| unsafe { crate::TA_WILLR_Lookback(timeperiod) })
 }
-pub fn s_willr() {}
+
 ///
 /// TA_WMA - Weighted Moving Average
 ///
@@ -2663,41 +18042,70 @@ pub fn s_willr() {}
 ///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
 ///        1.086670, 1.086630,
 /// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::wma(10,&close_prices);
+/// let (outs, begin) = rust_ta_lib::wrapper::wma(10,&close_prices).unwrap();
 /// for (index, value) in outs.iter().enumerate() {
 ///        println!("outs index {} = {}", begin + index as i32 + 1, value);
 ///  }
 /// ```
-pub fn wma(period: u32, close: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
+pub fn wma<T: TaFloat>(period: u32, close: &[T]) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    wma_range(period, close, ..)
+}
 
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_WMA(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the ma
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`wma`] restricted to the inputs whose index is in `range`
+///
+/// Inputs before the range are still read as warm-up history and the begin index counts from
+/// the start of the whole input.
+pub fn wma_range<T: TaFloat>(
+    period: u32,
+    close: &[T],
+    range: impl RangeBounds<usize>,
+) -> Result<(Vec<f64>, crate::TA_Integer), TaError> {
+    param!(period: int 2..=100000);
+    let ([out], out_begin) = compute(
+        close.len(),
+        range,
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.wma)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the ma
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )?;
 
-    (out, out_begin)
+    Ok((out, out_begin))
+}
+
+/// [`wma`] writing into caller-provided buffers instead of allocating
+///
+/// Each buffer needs room for the input length minus [`wma_lookback`] values. Returns the
+/// begin index and the number of values written.
+pub fn wma_into<T: TaFloat>(
+    period: u32,
+    close: &[T],
+    out: &mut [f64],
+) -> Result<(crate::TA_Integer, usize), TaError> {
+    param!(period: int 2..=100000);
+    compute_into(
+        close.len(),
+        || unsafe { crate::TA_WMA_Lookback(period) },
+        [out],
+        |start, end, out_begin, out_size, [out]| unsafe {
+            (T::FFI.wma)(
+                start,
+                end,
+                close.as_ptr(), // pointer to the first element of the close vector
+                period,         // period of the ma
+                out_begin,
+                out_size,
+                out,
+            )
+        },
+    )
 }
 ///
 /// TA_S_WMA - Weighted Moving Average
@@ -2708,46 +18116,17 @@ pub fn wma(period: u32, close: &Vec<f64>) -> (Vec<f64>, crate::TA_Integer) {
 /// optInTimePeriod:(From 2 to 100000)
 ///    Number of period
 //
-/// #Sample
-/// ```
-/// let close_prices: Vec<f32> = vec![
-///        1.087010, 1.087120, 1.087080, 1.087170, 1.087110, 1.087010, 1.087100, 1.087120, 1.087110,
-///        1.087080, 1.087000, 1.086630, 1.086630, 1.086610, 1.086630, 1.086640, 1.086650, 1.086650,
-///        1.086670, 1.086630,
-/// ];
-/// let (outs, begin) = rust_ta_lib::wrapper::s_wma(10,&close_prices);
-/// for (index, value) in outs.iter().enumerate() {
-///        println!("outs index {} = {}", begin + index as i32 + 1, value);
-///  }
-/// ```
-pub fn s_wma(period: u32, close: &Vec<f32>) -> (Vec<f64>, crate::TA_Integer) {
-    let mut out: Vec<f64> = Vec::with_capacity(close.len());
-    let mut out_begin: crate::TA_Integer = 0;
-    let mut out_size: crate::TA_Integer = 0;
-
-    unsafe {
-        crate::TA_Initialize();
-
-        let ret_code = crate::TA_S_WMA(
-            0,                      // index of the first close to use
-            close.len() as i32 - 1, // index of the last close to use
-            close.as_ptr(),         // pointer to the first element of the close vector
-            period as i32,          // period of the ma
-            &mut out_begin,         // set to index of the first close to have an atr value
-            &mut out_size,          // set to number of atr values computed
-            out.as_mut_ptr(),       // pointer to the first element of the output vector
-        );
-
-        match ret_code {
-            // Indicator was computed correctly, since the vector was filled by TA-lib C library,
-            // Rust doesn't know what is the new length of the vector, so we set it manually
-            // to the number of values returned by the TA_ATR call
-            crate::TA_RetCode_TA_SUCCESS => out.set_len(out_size as usize),
-            // An error occured
-            _ => panic!("Could not compute indicator, err: {:?}", ret_code),
-        }
-        crate::TA_Shutdown();
-    }
+/// [`wma`] with its outputs aligned on the input: value `i` belongs to input `i` and the
+/// warm-up values are NaN
+pub fn wma_aligned<T: TaFloat>(period: u32, close: &[T]) -> Result<Vec<f64>, TaError> {
+    let (out, begin) = wma(period, close)?;
+    let len = close.len();
+    Ok(align(out, begin, len, f64::NAN))
+}
 
-    (out, out_begin)
+/// Number of input values [`wma`] consumes before its first output with these parameters,
+/// which is the begin index it returns
+pub fn wma_lookback(period: u32) -> Result<usize, TaError> {
+    param!(period: int 2..=100000);
+    lookback(|| unsafe { crate::TA_WMA_Lookback(period) })
 }
@@ -0,0 +1,164 @@
+use std::collections::{BTreeMap, HashMap};
+
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{AbstractFunction, AbstractValues, MaType, OptionValue, TaError};
+
+fn close() -> Vec<f64> {
+    (0..120)
+        .map(|i| 10.0 + (i as f64 / 6.0).sin() + (i % 5) as f64 * 0.04)
+        .collect()
+}
+
+fn prices() -> HashMap<&'static str, Vec<f64>> {
+    let close = close();
+    HashMap::from([
+        ("open", close.iter().map(|c| c - 0.05).collect()),
+        ("high", close.iter().map(|c| c + 0.2).collect()),
+        ("low", close.iter().map(|c| c - 0.3).collect()),
+        ("close", close),
+    ])
+}
+
+#[test]
+fn functions_are_described_by_name() {
+    let macd = AbstractFunction::by_name("macd").unwrap();
+    assert_eq!(macd.name(), "MACD");
+    assert_eq!(macd.group(), "Momentum Indicators");
+    assert_eq!(macd.input_names().unwrap(), ["real"]);
+    assert_eq!(
+        macd.option_names().unwrap(),
+        ["fast_period", "slow_period", "signal_period"]
+    );
+    assert_eq!(
+        macd.output_names().unwrap(),
+        ["macd", "macd_signal", "macd_hist"]
+    );
+
+    let stoch = AbstractFunction::by_name("STOCH").unwrap();
+    assert_eq!(stoch.input_names().unwrap(), ["high", "low", "close"]);
+    assert_eq!(
+        stoch.option_names().unwrap(),
+        [
+            "fast_k_period",
+            "slow_k_period",
+            "slow_k_ma_type",
+            "slow_d_period",
+            "slow_d_ma_type"
+        ]
+    );
+
+    assert_eq!(
+        AbstractFunction::by_name("NOPE").unwrap_err(),
+        TaError::FuncNotFound
+    );
+    assert_eq!(
+        AbstractFunction::by_name("MA\0CD").unwrap_err(),
+        TaError::FuncNotFound
+    );
+}
+
+#[test]
+fn calls_match_the_wrappers() {
+    let close = close();
+    let macd = AbstractFunction::by_name("MACD").unwrap();
+    let options = HashMap::from([
+        ("fast_period", OptionValue::from(8)),
+        ("SlowPeriod", OptionValue::from(21u32)),
+        ("signal_period", OptionValue::Real(5.0)),
+    ]);
+    let outputs = macd.call([("real", &close)], &options).unwrap();
+    let expected = wrapper::macd(8, 21, 5, &close).unwrap();
+    assert_eq!(outputs.begin, expected.begin);
+    assert_eq!(outputs.real("macd").unwrap(), expected.macd);
+    assert_eq!(outputs.real("macd_signal").unwrap(), expected.signal);
+    assert_eq!(outputs.real("MACDHist").unwrap(), expected.hist);
+    assert_eq!(outputs.integer("macd"), None);
+
+    // options left out keep their TA-Lib default
+    let sma = AbstractFunction::by_name("SMA").unwrap();
+    let no_options: [(&str, f64); 0] = [];
+    let outputs = sma.call([("real", &close)], no_options).unwrap();
+    let (expected, begin) = wrapper::sma(30, &close).unwrap();
+    assert_eq!(outputs.begin, begin);
+    assert_eq!(
+        outputs.values,
+        BTreeMap::from([("real".to_string(), AbstractValues::Real(expected))])
+    );
+
+    let prices = prices();
+    let stoch = AbstractFunction::by_name("STOCH").unwrap();
+    let options = HashMap::from([("slow_k_ma_type", MaType::Ema)]);
+    let outputs = stoch.call(&prices, &options).unwrap_err();
+    // the open series is not an input of STOCH
+    assert_eq!(outputs, TaError::BadParam);
+    let hlc = prices.iter().filter(|(name, _)| **name != "open");
+    let outputs = stoch.call(hlc, &options).unwrap();
+    let expected = wrapper::stoch(
+        5,
+        3,
+        MaType::Ema,
+        3,
+        MaType::Sma,
+        &prices["high"],
+        &prices["low"],
+        &prices["close"],
+    )
+    .unwrap();
+    assert_eq!(outputs.begin, expected.begin);
+    assert_eq!(outputs.real("slow_k").unwrap(), expected.slow_k);
+    assert_eq!(outputs.real("slow_d").unwrap(), expected.slow_d);
+
+    let doji = AbstractFunction::by_name("CDLDOJI").unwrap();
+    let outputs = doji.call(&prices, no_options).unwrap();
+    let (expected, begin) = wrapper::cdldoji(
+        &prices["open"],
+        &prices["high"],
+        &prices["low"],
+        &prices["close"],
+    )
+    .unwrap();
+    assert_eq!(outputs.begin, begin);
+    let expected: Vec<i32> = expected.iter().map(|signal| signal.raw()).collect();
+    assert_eq!(outputs.integer("integer").unwrap(), expected);
+}
+
+#[test]
+fn bad_calls_are_reported() {
+    let close = close();
+    let macd = AbstractFunction::by_name("MACD").unwrap();
+    let no_options: [(&str, f64); 0] = [];
+    assert_eq!(
+        macd.call([("close", &close)], no_options).unwrap_err(),
+        TaError::InputNotAllInitialize
+    );
+    assert_eq!(
+        macd.call([("real", &close)], [("fast", 12)]).unwrap_err(),
+        TaError::BadParam
+    );
+    assert_eq!(
+        macd.call([("real", &close)], [("fast_period", 12.5)])
+            .unwrap_err(),
+        TaError::InvalidParamHolderType
+    );
+    // out of the range of the C library
+    assert_eq!(
+        macd.call([("real", &close)], [("fast_period", 1)])
+            .unwrap_err(),
+        TaError::BadParam
+    );
+
+    let prices = prices();
+    let doji = AbstractFunction::by_name("CDLDOJI").unwrap();
+    let mut short = prices.clone();
+    short.get_mut("low").unwrap().pop();
+    // the order of a HashMap decides which series is found to differ
+    assert!(matches!(
+        doji.call(&short, no_options).unwrap_err(),
+        TaError::InputLengthMismatch { .. }
+    ));
+
+    let empty: Vec<f64> = Vec::new();
+    let outputs = macd.call([("real", &empty)], no_options).unwrap();
+    assert_eq!(outputs.begin, 0);
+    assert_eq!(outputs.real("macd").unwrap(), &[] as &[f64]);
+}
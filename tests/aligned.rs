@@ -0,0 +1,53 @@
+mod common;
+
+use common::close;
+use rust_ta_lib::wrapper;
+use rust_ta_lib::CandleSignal;
+
+#[test]
+fn aligned_output_is_padded_with_nan() {
+    let close = close();
+    let (values, begin) = wrapper::sma(20, &close).unwrap();
+    let aligned = wrapper::sma_aligned(20, &close).unwrap();
+    assert_eq!(aligned.len(), close.len());
+    let begin = begin as usize;
+    assert!(aligned[..begin].iter().all(|value| value.is_nan()));
+    assert_eq!(&aligned[begin..], &values[..]);
+}
+
+#[test]
+fn every_output_of_a_multi_output_function_is_aligned() {
+    let close = close();
+    let bands = wrapper::bbands_aligned(20, &close, 2.0, 2.0, rust_ta_lib::MaType::Sma).unwrap();
+    let begin = bands.begin;
+    for output in [&bands.upper, &bands.middle, &bands.lower] {
+        assert_eq!(output.len(), close.len());
+        assert!(output[..begin as usize].iter().all(|value| value.is_nan()));
+        assert!(output[begin as usize..].iter().all(|value| !value.is_nan()));
+    }
+}
+
+#[test]
+fn integer_outputs_are_padded_with_none() {
+    let close = close();
+    let open: Vec<f64> = close.iter().map(|c| c - 0.05).collect();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.1).collect();
+    let low: Vec<f64> = open.iter().map(|o| o - 0.1).collect();
+    let (signals, begin) = wrapper::cdlengulfing(&open, &high, &low, &close).unwrap();
+    let aligned = wrapper::cdlengulfing_aligned(&open, &high, &low, &close).unwrap();
+    assert_eq!(aligned.len(), close.len());
+    assert!(aligned[..begin as usize].iter().all(Option::is_none));
+    let found: Vec<CandleSignal> = aligned[begin as usize..]
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+    assert_eq!(found, signals);
+}
+
+#[test]
+fn empty_input_gives_empty_aligned_output() {
+    let empty: Vec<f64> = Vec::new();
+    assert_eq!(wrapper::sma_aligned(20, &empty), Ok(Vec::new()));
+    assert_eq!(wrapper::maxindex_aligned(&empty, 30), Ok(Vec::new()));
+}
@@ -0,0 +1,51 @@
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{CandleSignal, TaError};
+
+#[test]
+fn raw_values_round_trip() {
+    for raw in [-200, -100, 0, 100, 200] {
+        assert_eq!(CandleSignal::from_raw(raw).raw(), raw);
+    }
+    assert_eq!(CandleSignal::from_raw(100), CandleSignal::Bullish(100));
+    assert_eq!(CandleSignal::from_raw(-100), CandleSignal::Bearish(100));
+    assert_eq!(CandleSignal::from_raw(0), CandleSignal::None);
+    assert!(!CandleSignal::None.is_some());
+}
+
+#[test]
+fn patterns_cover_the_input() {
+    let close: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 / 3.0).sin()).collect();
+    let open: Vec<f64> = close.iter().skip(1).chain([10.0].iter()).copied().collect();
+    let high: Vec<f64> = open
+        .iter()
+        .zip(&close)
+        .map(|(o, c)| o.max(*c) + 0.2)
+        .collect();
+    let low: Vec<f64> = open
+        .iter()
+        .zip(&close)
+        .map(|(o, c)| o.min(*c) - 0.2)
+        .collect();
+
+    let (signals, begin) = wrapper::cdlengulfing(&open, &high, &low, &close).unwrap();
+    assert!(begin as usize + signals.len() <= close.len());
+    let (signals, begin) = wrapper::cdlmorningstar(&open, &high, &low, &close, 0.3).unwrap();
+    assert!(begin as usize + signals.len() <= close.len());
+}
+
+#[test]
+fn mismatched_series_are_rejected() {
+    let close: Vec<f64> = (0..30).map(|i| 10.0 + (i as f64 / 3.0).sin()).collect();
+    let open: Vec<f64> = close.iter().map(|c| c - 0.1).collect();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.3).collect();
+    let low: Vec<f64> = close[..29].iter().map(|c| c - 0.3).collect();
+    let expected = Err(TaError::InputLengthMismatch {
+        expected: 30,
+        found: 29,
+    });
+    assert_eq!(wrapper::cdldoji(&open, &high, &low, &close), expected);
+    assert_eq!(
+        wrapper::cdlmorningstar(&open, &high, &low, &close, 0.3),
+        expected
+    );
+}
@@ -0,0 +1,96 @@
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{CandlePresets, CandleSettingType, CandleSettings, RangeType, TaError, TaLib};
+
+#[test]
+fn settings_are_kept_across_shutdowns() {
+    let open: [f64; 12] = [
+        1.0, 1.1, 1.2, 1.1, 1.0, 1.05, 1.1, 1.15, 1.2, 1.1, 1.0, 1.05,
+    ];
+    // the body of the 11th candle is a doji with the default factor only
+    let close: [f64; 12] = [
+        1.05, 1.15, 1.1, 1.0, 1.05, 1.1, 1.12, 1.2, 1.1, 1.0, 1.01, 1.1,
+    ];
+    let high: Vec<f64> = open
+        .iter()
+        .zip(close)
+        .map(|(o, c)| o.max(c) + 0.02)
+        .collect();
+    let low: Vec<f64> = open
+        .iter()
+        .zip(close)
+        .map(|(o, c)| o.min(c) - 0.02)
+        .collect();
+    let doji = || wrapper::cdldoji(&open, &high, &low, &close).unwrap();
+    let default = doji();
+
+    let mut settings = CandleSettings::default();
+    settings.body_doji.factor = 0.03;
+    settings.get_mut(CandleSettingType::Near).range_type = RangeType::RealBody;
+    settings.apply().unwrap();
+    let custom = doji();
+    assert_ne!(custom, default);
+
+    // the next computation initializes the library again with the same settings
+    drop(TaLib::init().unwrap());
+    assert_eq!(doji(), custom);
+    assert_eq!(CandleSettings::current(), settings);
+
+    CandleSettings::restore().unwrap();
+    assert_eq!(CandleSettings::current(), CandleSettings::DEFAULT);
+    assert_eq!(doji(), default);
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    let mut settings = CandleSettings::default();
+    settings.equal.factor = -0.1;
+    assert_eq!(
+        settings.apply(),
+        Err(TaError::ParamOutOfRange {
+            name: "factor",
+            range: "from 0.0 to 3e37"
+        })
+    );
+    settings.equal.factor = f64::NAN;
+    assert!(settings.apply().is_err());
+
+    let mut settings = CandleSettings::default();
+    settings.far.avg_period = 100001;
+    assert_eq!(
+        settings.apply(),
+        Err(TaError::ParamOutOfRange {
+            name: "avg_period",
+            range: "from 0 to 100000"
+        })
+    );
+}
+
+#[test]
+fn presets_are_found_by_name() {
+    let mut presets = CandlePresets::new();
+    assert_eq!(presets.get("default"), Some(CandleSettings::DEFAULT));
+    assert_eq!(presets.get("crypto"), None);
+    assert_eq!(presets.apply("crypto"), Err(TaError::BadParam));
+
+    let mut crypto = CandleSettings::default();
+    crypto.body_doji.avg_period = 20;
+    assert_eq!(presets.insert("crypto", crypto), None);
+    assert_eq!(presets.get("crypto"), Some(crypto));
+    assert_eq!(presets.names().collect::<Vec<_>>(), ["crypto", "default"]);
+
+    // the built-in default can be replaced
+    presets.insert("default", crypto);
+    assert_eq!(presets.get("default"), Some(crypto));
+    assert_eq!(presets.names().collect::<Vec<_>>(), ["crypto", "default"]);
+}
+
+#[test]
+fn setting_types_follow_the_c_enum() {
+    for (index, setting_type) in CandleSettingType::ALL.into_iter().enumerate() {
+        assert_eq!(setting_type.id() as usize, index);
+    }
+    assert_eq!(
+        CandleSettings::DEFAULT.get(CandleSettingType::BodyDoji),
+        &CandleSettings::DEFAULT.body_doji
+    );
+}
@@ -0,0 +1,23 @@
+// Synthetic series shared by the integration tests, each test binary uses a part of them
+#![allow(dead_code)]
+
+pub const LEN: usize = 300;
+
+// Closes oscillating around 10 with a little noise
+pub fn close() -> Vec<f64> {
+    (0..LEN)
+        .map(|i| 10.0 + (i as f64 / 9.0).sin() + (i % 5) as f64 * 0.02)
+        .collect()
+}
+
+pub fn high() -> Vec<f64> {
+    close().iter().map(|c| c + 0.3).collect()
+}
+
+pub fn low() -> Vec<f64> {
+    close().iter().map(|c| c - 0.3).collect()
+}
+
+pub fn single(values: Vec<f64>) -> Vec<f32> {
+    values.into_iter().map(|v| v as f32).collect()
+}
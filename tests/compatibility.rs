@@ -0,0 +1,100 @@
+use std::thread;
+
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{Compatibility, MaType, TaLib};
+
+fn close() -> Vec<f64> {
+    (0..200)
+        .map(|i| 10.0 + (i as f64 / 7.0).sin() + (i % 4) as f64 * 0.03)
+        .collect()
+}
+
+fn c_value() -> Compatibility {
+    Compatibility::try_from(unsafe { rust_ta_lib::TA_GetCompatibility() }).unwrap()
+}
+
+#[test]
+fn global_mode_is_kept_across_calls_and_shutdowns() {
+    let close = close();
+    Compatibility::set(Compatibility::Metastock).unwrap();
+    assert_eq!(Compatibility::get(), Compatibility::Metastock);
+    wrapper::ema(&close, 10).unwrap();
+    assert_eq!(c_value(), Compatibility::Metastock);
+
+    // a per-call mode leaves the global one alone
+    wrapper::ema_compat(&close, 10, Compatibility::Default).unwrap();
+    assert_eq!(Compatibility::get(), Compatibility::Metastock);
+    assert_eq!(c_value(), Compatibility::Metastock);
+
+    drop(TaLib::init().unwrap());
+    // the next computation initializes the library again
+    wrapper::ema(&close, 10).unwrap();
+    assert_eq!(c_value(), Compatibility::Metastock);
+
+    Compatibility::set(Compatibility::Default).unwrap();
+    assert_eq!(c_value(), Compatibility::Default);
+}
+
+#[test]
+fn per_call_modes_do_not_leak_between_threads() {
+    let close = close();
+    let expected = wrapper::macd_compat(12, 26, 9, &close, Compatibility::Metastock).unwrap();
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..50 {
+                    let out =
+                        wrapper::macd_compat(12, 26, 9, &close, Compatibility::Metastock).unwrap();
+                    assert_eq!(out, expected);
+                    wrapper::rsi(14, &close).unwrap();
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn functions_taking_a_ma_type_have_a_compat_form() {
+    let close = close();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.2).collect();
+    let low: Vec<f64> = close.iter().map(|c| c - 0.2).collect();
+    let periods: Vec<f64> = (0..close.len()).map(|i| (5 + i % 10) as f64).collect();
+    let (default, metastock) = (Compatibility::Default, Compatibility::Metastock);
+
+    let bbands = |mode| wrapper::bbands_compat(10, &close, 2.0, 2.0, MaType::Ema, mode).unwrap();
+    assert_ne!(bbands(default), bbands(metastock));
+    let stoch = |mode| {
+        wrapper::stoch_compat(
+            14,
+            3,
+            MaType::Ema,
+            3,
+            MaType::Ema,
+            &high,
+            &low,
+            &close,
+            mode,
+        )
+        .unwrap()
+    };
+    assert_ne!(stoch(default), stoch(metastock));
+    let stochf =
+        |mode| wrapper::stochf_compat(&high, &low, &close, 14, 3, MaType::Ema, mode).unwrap();
+    assert_ne!(stochf(default), stochf(metastock));
+    let stochrsi = |mode| wrapper::stochrsi_compat(&close, 14, 30, 5, MaType::Ema, mode).unwrap();
+    assert_ne!(stochrsi(default), stochrsi(metastock));
+    let mavp = |mode| wrapper::mavp_compat(&close, &periods, 5, 15, MaType::Ema, mode).unwrap();
+    assert_ne!(mavp(default), mavp(metastock));
+}
+
+#[test]
+fn c_values_convert_back() {
+    for compatibility in [Compatibility::Default, Compatibility::Metastock] {
+        let value = rust_ta_lib::TA_Compatibility::from(compatibility);
+        assert_eq!(Compatibility::try_from(value), Ok(compatibility));
+    }
+    assert_eq!(
+        Compatibility::try_from(7),
+        Err(rust_ta_lib::TaError::BadParam)
+    );
+}
@@ -0,0 +1,138 @@
+use rust_ta_lib::wrapper;
+use rust_ta_lib::TaLib;
+
+fn close() -> Vec<f64> {
+    (0..300)
+        .map(|i| 50.0 + (i as f64 / 10.0).sin() * 5.0 + i as f64 * 0.02)
+        .collect()
+}
+
+type RealFunc = unsafe extern "C" fn(
+    i32,
+    i32,
+    *const f64,
+    *mut i32,
+    *mut i32,
+    *mut f64,
+) -> rust_ta_lib::TA_RetCode;
+
+// Output of a TA_HT_* function with a single real output, called directly
+fn c_real(func: RealFunc, input: &[f64]) -> (Vec<f64>, i32) {
+    let mut out = vec![0.0; input.len()];
+    let (mut begin, mut size) = (0, 0);
+    let ret_code = unsafe {
+        func(
+            0,
+            input.len() as i32 - 1,
+            input.as_ptr(),
+            &mut begin,
+            &mut size,
+            out.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    out.truncate(size as usize);
+    (out, begin)
+}
+
+#[test]
+fn single_outputs_match_the_c_library() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    assert_eq!(
+        wrapper::ht_dcperiod(&close).unwrap(),
+        c_real(rust_ta_lib::TA_HT_DCPERIOD, &close)
+    );
+    assert_eq!(
+        wrapper::ht_dcphase(&close).unwrap(),
+        c_real(rust_ta_lib::TA_HT_DCPHASE, &close)
+    );
+    assert_eq!(
+        wrapper::ht_trendline(&close).unwrap(),
+        c_real(rust_ta_lib::TA_HT_TRENDLINE, &close)
+    );
+}
+
+#[test]
+fn pairs_match_the_c_library() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let end = close.len() as i32 - 1;
+    let (mut first, mut second) = (vec![0.0; close.len()], vec![0.0; close.len()]);
+    let (mut begin, mut size) = (0, 0);
+
+    let ret_code = unsafe {
+        rust_ta_lib::TA_HT_PHASOR(
+            0,
+            end,
+            close.as_ptr(),
+            &mut begin,
+            &mut size,
+            first.as_mut_ptr(),
+            second.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    let phasor = wrapper::ht_phasor(&close).unwrap();
+    assert_eq!(phasor.begin, begin);
+    assert_eq!(phasor.inphase, first[..size as usize]);
+    assert_eq!(phasor.quadrature, second[..size as usize]);
+
+    let ret_code = unsafe {
+        rust_ta_lib::TA_HT_SINE(
+            0,
+            end,
+            close.as_ptr(),
+            &mut begin,
+            &mut size,
+            first.as_mut_ptr(),
+            second.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    let sine = wrapper::ht_sine(&close).unwrap();
+    assert_eq!(sine.begin, begin);
+    assert_eq!(sine.sine, first[..size as usize]);
+    assert_eq!(sine.leadsine, second[..size as usize]);
+}
+
+#[test]
+fn trend_mode_is_read_as_a_flag() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let mut modes = vec![0; close.len()];
+    let (mut begin, mut size) = (0, 0);
+    let ret_code = unsafe {
+        rust_ta_lib::TA_HT_TRENDMODE(
+            0,
+            close.len() as i32 - 1,
+            close.as_ptr(),
+            &mut begin,
+            &mut size,
+            modes.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    modes.truncate(size as usize);
+    // 1 while the market trends, 0 while it cycles
+    assert!(modes.iter().all(|mode| *mode == 0 || *mode == 1));
+    let expected: Vec<bool> = modes.iter().map(|mode| *mode == 1).collect();
+    assert_eq!(wrapper::ht_trendmode(&close).unwrap(), (expected, begin));
+
+    let close: Vec<f32> = close.iter().map(|value| *value as f32).collect();
+    let mut modes = vec![0; close.len()];
+    let ret_code = unsafe {
+        rust_ta_lib::TA_S_HT_TRENDMODE(
+            0,
+            close.len() as i32 - 1,
+            close.as_ptr(),
+            &mut begin,
+            &mut size,
+            modes.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    modes.truncate(size as usize);
+    let expected: Vec<bool> = modes.iter().map(|mode| *mode == 1).collect();
+    assert_eq!(wrapper::ht_trendmode(&close).unwrap(), (expected, begin));
+}
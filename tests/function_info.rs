@@ -0,0 +1,123 @@
+use rust_ta_lib::{FunctionInfo, InputKind, OptInputRange, OutputFlag, OutputKind};
+
+// the groups TA-Lib sorts its functions into
+const GROUPS: [&str; 10] = [
+    "Math Operators",
+    "Math Transform",
+    "Overlap Studies",
+    "Volatility Indicators",
+    "Momentum Indicators",
+    "Cycle Indicators",
+    "Volume Indicators",
+    "Pattern Recognition",
+    "Statistic Functions",
+    "Price Transform",
+];
+
+#[test]
+fn catalog_describes_every_function() {
+    let functions = FunctionInfo::all().unwrap();
+    assert!(!functions.is_empty());
+    for function in &functions {
+        assert!(
+            GROUPS.contains(&function.group.as_str()),
+            "{} is in the unknown group {}",
+            function.name,
+            function.group
+        );
+        assert!(
+            !function.inputs.is_empty(),
+            "{} has no input",
+            function.name
+        );
+        assert!(
+            !function.outputs.is_empty(),
+            "{} has no output",
+            function.name
+        );
+        for opt_input in &function.opt_inputs {
+            let default = opt_input.default;
+            let accepted = match &opt_input.range {
+                OptInputRange::Real { min, max, .. } => (*min..=*max).contains(&default),
+                OptInputRange::Integer { min, max } => {
+                    (*min as f64..=*max as f64).contains(&default)
+                }
+                OptInputRange::RealList(values) => {
+                    values.iter().any(|(value, _)| *value == default)
+                }
+                OptInputRange::IntegerList(values) => {
+                    values.iter().any(|(value, _)| *value as f64 == default)
+                }
+            };
+            assert!(
+                accepted,
+                "default {} of {}.{} is out of range",
+                default, function.name, opt_input.name
+            );
+        }
+    }
+
+    let macd = functions.iter().find(|f| f.name == "MACD").unwrap();
+    assert_eq!(macd.group, "Momentum Indicators");
+    assert_eq!(macd.inputs[0].kind, InputKind::Real);
+    assert_eq!(macd.inputs[0].series, ["real"]);
+    assert_eq!(macd.opt_inputs[0].name, "fast_period");
+    assert_eq!(macd.opt_inputs[0].display_name, "Fast Period");
+    assert_eq!(macd.opt_inputs[0].default, 12.0);
+    assert_eq!(
+        macd.opt_inputs[0].range,
+        OptInputRange::Integer {
+            min: 2,
+            max: 100000
+        }
+    );
+    assert_eq!(macd.outputs[2].name, "macd_hist");
+    assert_eq!(macd.outputs[2].kind, OutputKind::Real);
+    assert_eq!(macd.outputs[2].flags, [OutputFlag::Histo]);
+
+    let stoch = functions.iter().find(|f| f.name == "STOCH").unwrap();
+    assert_eq!(stoch.inputs[0].kind, InputKind::Price);
+    assert_eq!(stoch.inputs[0].series, ["high", "low", "close"]);
+    match &stoch.opt_inputs[2].range {
+        OptInputRange::IntegerList(ma_types) => {
+            assert_eq!(ma_types.len(), 9);
+            assert_eq!(ma_types[1], (1, "EMA".to_string()));
+        }
+        range => panic!("MA type given as {:?}", range),
+    }
+
+    let doji = functions.iter().find(|f| f.name == "CDLDOJI").unwrap();
+    assert_eq!(doji.group, "Pattern Recognition");
+    assert_eq!(doji.outputs[0].kind, OutputKind::Integer);
+}
+
+#[test]
+fn wrapper_covers_every_function() {
+    let wrapper = include_str!("../src/wrapper.rs");
+    let missing: Vec<String> = FunctionInfo::all()
+        .unwrap()
+        .into_iter()
+        .map(|function| function.name.to_lowercase())
+        .filter(|name| !wrapper.contains(&format!("\npub fn {}<", name)))
+        .collect();
+    assert!(missing.is_empty(), "no wrapper for {:?}", missing);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn catalog_is_exported() {
+    let functions = FunctionInfo::all().unwrap();
+    let json = serde_json::to_value(&functions).unwrap();
+    let macd = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == "MACD")
+        .unwrap();
+    assert_eq!(macd["opt_inputs"][1]["name"], "slow_period");
+    assert_eq!(macd["outputs"][2]["flags"][0], "Histo");
+    assert_eq!(
+        serde_json::from_value::<Vec<FunctionInfo>>(json).unwrap(),
+        functions
+    );
+}
@@ -0,0 +1,66 @@
+use std::thread;
+
+use rust_ta_lib::wrapper;
+use rust_ta_lib::TaLib;
+
+fn prices() -> Vec<f64> {
+    (0..500)
+        .map(|i| 1.08 + (i as f64 / 7.0).sin() * 0.01 + (i % 13) as f64 * 0.0001)
+        .collect()
+}
+
+#[test]
+fn wrappers_initialize_lazily() {
+    let close = prices();
+    let (values, begin) = wrapper::sma(10, &close).unwrap();
+    assert!(begin >= 0);
+    assert!(values.len() <= close.len());
+}
+
+#[test]
+fn guard_keeps_library_initialized() {
+    let first = TaLib::init().unwrap();
+    let second = TaLib::init().unwrap();
+    assert!(TaLib::is_initialized());
+    drop(first);
+    assert!(TaLib::is_initialized());
+    // computing while a guard is alive must not tear the library down
+    wrapper::rsi(14, &prices()).unwrap();
+    assert!(TaLib::is_initialized());
+    drop(second);
+}
+
+#[test]
+fn concurrent_calls_match_single_threaded_results() {
+    let close = prices();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.001).collect();
+    let low: Vec<f64> = close.iter().map(|c| c - 0.001).collect();
+    let sma = wrapper::sma(20, &close).unwrap();
+    let rsi = wrapper::rsi(14, &close).unwrap();
+    let atr = wrapper::atr(14, &high, &low, &close).unwrap();
+    let bbands = wrapper::bbands(20, &close, 2.0, 2.0, rust_ta_lib::MaType::Sma).unwrap();
+
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..200 {
+                    assert_eq!(wrapper::sma(20, &close).unwrap(), sma);
+                    assert_eq!(wrapper::rsi(14, &close).unwrap(), rsi);
+                    assert_eq!(wrapper::atr(14, &high, &low, &close).unwrap(), atr);
+                    assert_eq!(
+                        wrapper::bbands(20, &close, 2.0, 2.0, rust_ta_lib::MaType::Sma).unwrap(),
+                        bbands
+                    );
+                }
+            });
+        }
+        // guards coming and going must not disturb computations running on other threads
+        scope.spawn(|| {
+            for _ in 0..200 {
+                let talib = TaLib::init().unwrap();
+                assert!(TaLib::is_initialized());
+                drop(talib);
+            }
+        });
+    });
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::{close, high, low};
+use rust_ta_lib::wrapper;
+use rust_ta_lib::TaError;
+
+#[test]
+fn into_matches_the_allocating_wrapper() {
+    let close = close();
+    let (expected, expected_begin) = wrapper::sma(20, &close).unwrap();
+    let mut out = vec![0.0; close.len() - wrapper::sma_lookback(20).unwrap()];
+    let (begin, count) = wrapper::sma_into(20, &close, &mut out).unwrap();
+    assert_eq!(begin, expected_begin);
+    assert_eq!(&out[..count], &expected[..]);
+
+    let expected = wrapper::macd(12, 26, 9, &close).unwrap();
+    let required = close.len() - wrapper::macd_lookback(12, 26, 9).unwrap();
+    let (mut out_macd, mut out_signal, mut out_hist) = (
+        vec![0.0; required],
+        vec![0.0; required],
+        vec![0.0; required],
+    );
+    let (begin, count) = wrapper::macd_into(
+        12,
+        26,
+        9,
+        &close,
+        &mut out_macd,
+        &mut out_signal,
+        &mut out_hist,
+    )
+    .unwrap();
+    assert_eq!(begin, expected.begin);
+    assert_eq!(&out_macd[..count], &expected.macd[..]);
+    assert_eq!(&out_signal[..count], &expected.signal[..]);
+    assert_eq!(&out_hist[..count], &expected.hist[..]);
+}
+
+#[test]
+fn buffers_can_be_reused() {
+    let close = close();
+    let mut out = vec![0.0; close.len()];
+    for end in 100..close.len() {
+        let (_, count) = wrapper::rsi_into(14, &close[..end], &mut out).unwrap();
+        let (expected, _) = wrapper::rsi(14, &close[..end]).unwrap();
+        assert_eq!(&out[..count], &expected[..]);
+    }
+}
+
+#[test]
+fn small_buffers_are_rejected() {
+    let close = close();
+    let required = close.len() - wrapper::sma_lookback(20).unwrap();
+    let mut out = vec![0.0; required - 1];
+    assert_eq!(
+        wrapper::sma_into(20, &close, &mut out),
+        Err(TaError::OutputTooSmall {
+            required,
+            found: required - 1
+        })
+    );
+}
+
+#[test]
+fn mismatched_series_are_rejected() {
+    let (close, high) = (close(), high());
+    let low = low()[1..].to_vec();
+    let mut out = vec![0.0; close.len()];
+    assert_eq!(
+        wrapper::willr_into(&high, &low, &close, 14, &mut out),
+        Err(TaError::InputLengthMismatch {
+            expected: 300,
+            found: 299
+        })
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{close, high, low};
+use rust_ta_lib::wrapper;
+use rust_ta_lib::MaType;
+use rust_ta_lib::TaError;
+
+#[test]
+fn lookback_is_the_begin_index() {
+    let (close, high, low) = (close(), high(), low());
+
+    let (_, begin) = wrapper::sma(20, &close).unwrap();
+    assert_eq!(wrapper::sma_lookback(20).unwrap(), begin as usize);
+    let (_, begin) = wrapper::ema(&close, 30).unwrap();
+    assert_eq!(wrapper::ema_lookback(30).unwrap(), begin as usize);
+    let begin = wrapper::macd(12, 26, 9, &close).unwrap().begin;
+    assert_eq!(wrapper::macd_lookback(12, 26, 9).unwrap(), begin as usize);
+    let begin = wrapper::bbands(20, &close, 2.0, 2.0, MaType::Sma)
+        .unwrap()
+        .begin;
+    assert_eq!(
+        wrapper::bbands_lookback(20, 2.0, 2.0, MaType::Sma).unwrap(),
+        begin as usize
+    );
+    let begin = wrapper::stoch(14, 3, MaType::Ema, 3, MaType::Sma, &high, &low, &close)
+        .unwrap()
+        .begin;
+    assert_eq!(
+        wrapper::stoch_lookback(14, 3, MaType::Ema, 3, MaType::Sma).unwrap(),
+        begin as usize
+    );
+    let (_, begin) = wrapper::atr(14, &high, &low, &close).unwrap();
+    assert_eq!(wrapper::atr_lookback(14).unwrap(), begin as usize);
+    let (_, begin) = wrapper::cdlmorningstar(&close, &high, &low, &close, 0.3).unwrap();
+    assert_eq!(
+        wrapper::cdlmorningstar_lookback(0.3).unwrap(),
+        begin as usize
+    );
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    assert_eq!(
+        wrapper::sma_lookback(1),
+        Err(TaError::ParamOutOfRange {
+            name: "period",
+            range: "from 2 to 100000"
+        })
+    );
+    assert_eq!(
+        wrapper::macd_lookback(12, 1, 9),
+        Err(TaError::ParamOutOfRange {
+            name: "slow_period",
+            range: "from 2 to 100000"
+        })
+    );
+}
@@ -0,0 +1,33 @@
+use rust_ta_lib::MaType;
+use rust_ta_lib::TaError;
+
+#[test]
+fn names_round_trip() {
+    for ma_type in MaType::ALL {
+        assert_eq!(ma_type.to_string().parse::<MaType>(), Ok(ma_type));
+        assert_eq!(
+            ma_type.to_string().to_lowercase().parse::<MaType>(),
+            Ok(ma_type)
+        );
+    }
+    assert_eq!("HMA".parse::<MaType>(), Err(TaError::BadParam));
+}
+
+#[test]
+fn converts_to_the_c_constants() {
+    assert_eq!(
+        rust_ta_lib::TA_MAType::from(MaType::Sma),
+        rust_ta_lib::TA_MAType_TA_MAType_SMA
+    );
+    assert_eq!(
+        rust_ta_lib::TA_MAType::from(MaType::T3),
+        rust_ta_lib::TA_MAType_TA_MAType_T3
+    );
+    for ma_type in MaType::ALL {
+        assert_eq!(
+            MaType::try_from(rust_ta_lib::TA_MAType::from(ma_type)),
+            Ok(ma_type)
+        );
+    }
+    assert_eq!(MaType::try_from(42), Err(TaError::BadParam));
+}
@@ -0,0 +1,32 @@
+use rust_ta_lib::wrapper;
+
+#[test]
+fn index_functions_point_into_the_input() {
+    let input: Vec<f64> = (0..60).map(|i| (i as f64 / 4.0).sin()).collect();
+
+    let (maxidx, begin) = wrapper::maxindex(&input, 10).unwrap();
+    let (max, _) = wrapper::max(&input, 10).unwrap();
+    for (i, index) in maxidx.iter().enumerate() {
+        assert!(*index <= begin as usize + i);
+        assert_eq!(input[*index], max[i]);
+    }
+
+    let index = wrapper::minmaxindex(&input, 10).unwrap();
+    let value = wrapper::minmax(&input, 10).unwrap();
+    let begin = index.begin as usize;
+    for i in 0..index.min.len() {
+        assert!(index.min[i] <= begin + i && index.max[i] <= begin + i);
+        assert_eq!(input[index.min[i]], value.min[i]);
+        assert_eq!(input[index.max[i]], value.max[i]);
+    }
+}
+
+#[test]
+fn acos_is_the_inverse_of_cos() {
+    let input: Vec<f64> = (0..20).map(|i| i as f64 / 10.0).collect();
+    let (cos, _) = wrapper::cos(&input).unwrap();
+    let (acos, _) = wrapper::acos(&cos).unwrap();
+    for (value, back) in input.iter().zip(&acos) {
+        assert!((value - back).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,175 @@
+use rust_ta_lib::wrapper::{self, Macd};
+use rust_ta_lib::{MaType, TA_RetCode, TaError, TaLib};
+
+fn close() -> Vec<f64> {
+    (0..150)
+        .map(|i| 20.0 + (i as f64 / 7.0).sin() * 3.0 + (i % 4) as f64 * 0.15)
+        .collect()
+}
+
+fn high_low(close: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let high = close.iter().map(|c| c + 0.4).collect();
+    let low = close.iter().map(|c| c - 0.5).collect();
+    (high, low)
+}
+
+type HlcFunc = unsafe extern "C" fn(
+    i32,
+    i32,
+    *const f64,
+    *const f64,
+    *const f64,
+    i32,
+    *mut i32,
+    *mut i32,
+    *mut f64,
+) -> TA_RetCode;
+
+// Output of a TA-Lib function of high, low, close and a period, called directly
+fn c_hlc(func: HlcFunc, high: &[f64], low: &[f64], close: &[f64], period: i32) -> (Vec<f64>, i32) {
+    let mut out = vec![0.0; close.len()];
+    let (mut begin, mut size) = (0, 0);
+    let ret_code = unsafe {
+        func(
+            0,
+            close.len() as i32 - 1,
+            high.as_ptr(),
+            low.as_ptr(),
+            close.as_ptr(),
+            period,
+            &mut begin,
+            &mut size,
+            out.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    out.truncate(size as usize);
+    (out, begin)
+}
+
+// The three outputs of TA_MACDEXT or TA_MACDFIX, `call` gets the end index and the outputs
+fn c_macd(
+    len: usize,
+    call: impl FnOnce(i32, &mut i32, &mut i32, *mut f64, *mut f64, *mut f64) -> TA_RetCode,
+) -> Macd {
+    let (mut macd, mut signal, mut hist) = (vec![0.0; len], vec![0.0; len], vec![0.0; len]);
+    let (mut begin, mut size) = (0, 0);
+    let ret_code = call(
+        len as i32 - 1,
+        &mut begin,
+        &mut size,
+        macd.as_mut_ptr(),
+        signal.as_mut_ptr(),
+        hist.as_mut_ptr(),
+    );
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    for out in [&mut macd, &mut signal, &mut hist] {
+        out.truncate(size as usize);
+    }
+    Macd {
+        macd,
+        signal,
+        hist,
+        begin,
+    }
+}
+
+#[test]
+fn directional_indicators_match_the_c_library() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let (high, low) = high_low(&close);
+    assert_eq!(
+        wrapper::adxr(&high, &low, &close, 14).unwrap(),
+        c_hlc(rust_ta_lib::TA_ADXR, &high, &low, &close, 14)
+    );
+    assert_eq!(
+        wrapper::dx(&high, &low, &close, 14).unwrap(),
+        c_hlc(rust_ta_lib::TA_DX, &high, &low, &close, 14)
+    );
+    assert_eq!(
+        wrapper::plus_di(&high, &low, &close, 10).unwrap(),
+        c_hlc(rust_ta_lib::TA_PLUS_DI, &high, &low, &close, 10)
+    );
+    assert_eq!(
+        wrapper::minus_di(&high, &low, &close, 10).unwrap(),
+        c_hlc(rust_ta_lib::TA_MINUS_DI, &high, &low, &close, 10)
+    );
+
+    assert_eq!(
+        wrapper::adxr(&high, &low[1..], &close, 14),
+        Err(TaError::InputLengthMismatch {
+            expected: 150,
+            found: 149
+        })
+    );
+}
+
+#[test]
+fn imi_matches_the_c_library() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let open: Vec<f64> = close.iter().map(|c| c - 0.2).collect();
+    let mut out = vec![0.0; close.len()];
+    let (mut begin, mut size) = (0, 0);
+    let ret_code = unsafe {
+        rust_ta_lib::TA_IMI(
+            0,
+            close.len() as i32 - 1,
+            open.as_ptr(),
+            close.as_ptr(),
+            14,
+            &mut begin,
+            &mut size,
+            out.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret_code, rust_ta_lib::TA_RetCode_TA_SUCCESS);
+    out.truncate(size as usize);
+    assert_eq!(wrapper::imi(&open, &close, 14).unwrap(), (out, begin));
+    assert!(wrapper::imi(&open[1..], &close, 14).is_err());
+}
+
+#[test]
+fn macdext_takes_one_ma_type_per_line() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let (sma, ema, wma) = (MaType::Sma, MaType::Ema, MaType::Wma);
+    let expected = c_macd(close.len(), |end, begin, size, macd, signal, hist| unsafe {
+        rust_ta_lib::TA_MACDEXT(
+            0,
+            end,
+            close.as_ptr(),
+            8,
+            ema.into(),
+            21,
+            sma.into(),
+            5,
+            wma.into(),
+            begin,
+            size,
+            macd,
+            signal,
+            hist,
+        )
+    });
+    assert_eq!(
+        wrapper::macdext(&close, 8, ema, 21, sma, 5, wma).unwrap(),
+        expected
+    );
+    // each line really uses its own type
+    assert_ne!(
+        wrapper::macdext(&close, 8, sma, 21, ema, 5, wma).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn macdfix_matches_the_c_library() {
+    let _talib = TaLib::init().unwrap();
+    let close = close();
+    let expected = c_macd(close.len(), |end, begin, size, macd, signal, hist| unsafe {
+        rust_ta_lib::TA_MACDFIX(0, end, close.as_ptr(), 9, begin, size, macd, signal, hist)
+    });
+    assert_eq!(wrapper::macdfix(&close, 9).unwrap(), expected);
+}
@@ -0,0 +1,230 @@
+mod common;
+
+use common::{close, high, low, single, LEN};
+use rust_ta_lib::wrapper;
+use rust_ta_lib::MaType;
+
+// Every output vector must hold one value per input from the begin index to the end
+fn assert_filled(name: &str, begin: i32, outputs: &[usize]) {
+    assert!(begin >= 0, "{}: negative begin index", name);
+    for (i, len) in outputs.iter().enumerate() {
+        assert_eq!(
+            *len,
+            LEN - begin as usize,
+            "{}: output {} is not filled",
+            name,
+            i
+        );
+    }
+}
+
+macro_rules! check {
+    ($name:expr, $call:expr, [$($output:ident),+]) => {{
+        let out = $call.unwrap();
+        assert_filled($name, out.begin, &[$(out.$output.len()),+]);
+    }};
+}
+
+#[test]
+fn accbands() {
+    check!(
+        "accbands",
+        wrapper::accbands(20, &high(), &low(), &close()),
+        [upper, middle, lower]
+    );
+    check!(
+        "accbands::<f32>",
+        wrapper::accbands(20, &single(high()), &single(low()), &single(close())),
+        [upper, middle, lower]
+    );
+}
+
+#[test]
+fn aroon() {
+    check!("aroon", wrapper::aroon(&high(), &low(), 14), [down, up]);
+    check!(
+        "aroon::<f32>",
+        wrapper::aroon(&single(high()), &single(low()), 14),
+        [down, up]
+    );
+}
+
+#[test]
+fn bbands() {
+    check!(
+        "bbands",
+        wrapper::bbands(5, &close(), 2.0, 2.0, MaType::Sma),
+        [upper, middle, lower]
+    );
+    check!(
+        "bbands::<f32>",
+        wrapper::bbands(5, &single(close()), 2.0, 2.0, MaType::Sma),
+        [upper, middle, lower]
+    );
+}
+
+#[test]
+fn ht_phasor() {
+    let out = wrapper::ht_phasor(&close()).unwrap();
+    assert_filled(
+        "ht_phasor",
+        out.begin,
+        &[out.inphase.len(), out.quadrature.len()],
+    );
+    let out = wrapper::ht_phasor(&single(close())).unwrap();
+    assert_filled(
+        "ht_phasor::<f32>",
+        out.begin,
+        &[out.inphase.len(), out.quadrature.len()],
+    );
+}
+
+#[test]
+fn ht_sine() {
+    let out = wrapper::ht_sine(&close()).unwrap();
+    assert_filled("ht_sine", out.begin, &[out.sine.len(), out.leadsine.len()]);
+    let out = wrapper::ht_sine(&single(close())).unwrap();
+    assert_filled(
+        "ht_sine::<f32>",
+        out.begin,
+        &[out.sine.len(), out.leadsine.len()],
+    );
+}
+
+#[test]
+fn macd() {
+    check!(
+        "macd",
+        wrapper::macd(12, 26, 9, &close()),
+        [macd, signal, hist]
+    );
+    check!(
+        "macd::<f32>",
+        wrapper::macd(12, 26, 9, &single(close())),
+        [macd, signal, hist]
+    );
+}
+
+#[test]
+fn macdext() {
+    check!(
+        "macdext",
+        wrapper::macdext(&close(), 12, MaType::Sma, 26, MaType::Sma, 9, MaType::Sma),
+        [macd, signal, hist]
+    );
+    check!(
+        "macdext::<f32>",
+        wrapper::macdext(
+            &single(close()),
+            12,
+            MaType::Sma,
+            26,
+            MaType::Sma,
+            9,
+            MaType::Sma
+        ),
+        [macd, signal, hist]
+    );
+}
+
+#[test]
+fn macdfix() {
+    check!(
+        "macdfix",
+        wrapper::macdfix(&close(), 9),
+        [macd, signal, hist]
+    );
+    check!(
+        "macdfix::<f32>",
+        wrapper::macdfix(&single(close()), 9),
+        [macd, signal, hist]
+    );
+}
+
+#[test]
+fn mama() {
+    check!("mama", wrapper::mama(&close(), 0.5, 0.05), [mama, fama]);
+    check!(
+        "mama::<f32>",
+        wrapper::mama(&single(close()), 0.5, 0.05),
+        [mama, fama]
+    );
+}
+
+#[test]
+fn minmax() {
+    check!("minmax", wrapper::minmax(&close(), 30), [min, max]);
+    check!(
+        "minmax::<f32>",
+        wrapper::minmax(&single(close()), 30),
+        [min, max]
+    );
+    check!(
+        "minmaxindex",
+        wrapper::minmaxindex(&close(), 30),
+        [min, max]
+    );
+    check!(
+        "minmaxindex::<f32>",
+        wrapper::minmaxindex(&single(close()), 30),
+        [min, max]
+    );
+}
+
+#[test]
+fn stoch() {
+    check!(
+        "stoch",
+        wrapper::stoch(5, 3, MaType::Sma, 3, MaType::Sma, &high(), &low(), &close()),
+        [slow_k, slow_d]
+    );
+    check!(
+        "stoch::<f32>",
+        wrapper::stoch(
+            5,
+            3,
+            MaType::Sma,
+            3,
+            MaType::Sma,
+            &single(high()),
+            &single(low()),
+            &single(close())
+        ),
+        [slow_k, slow_d]
+    );
+}
+
+#[test]
+fn stochf() {
+    check!(
+        "stochf",
+        wrapper::stochf(&high(), &low(), &close(), 5, 3, MaType::Sma),
+        [fast_k, fast_d]
+    );
+    check!(
+        "stochf::<f32>",
+        wrapper::stochf(
+            &single(high()),
+            &single(low()),
+            &single(close()),
+            5,
+            3,
+            MaType::Sma
+        ),
+        [fast_k, fast_d]
+    );
+}
+
+#[test]
+fn stochrsi() {
+    check!(
+        "stochrsi",
+        wrapper::stochrsi(&close(), 14, 5, 3, MaType::Sma),
+        [fast_k, fast_d]
+    );
+    check!(
+        "stochrsi::<f32>",
+        wrapper::stochrsi(&single(close()), 14, 5, 3, MaType::Sma),
+        [fast_k, fast_d]
+    );
+}
@@ -0,0 +1,28 @@
+use rust_ta_lib::wrapper::{self, SarExtOptions};
+use rust_ta_lib::TaError;
+
+#[test]
+fn mavp_requires_one_period_per_bar() {
+    let close: Vec<f64> = (0..40).map(|i| 10.0 + (i as f64 / 5.0).sin()).collect();
+    let periods = vec![5.0; 39];
+    assert_eq!(
+        wrapper::mavp(&close, &periods, 2, 30, rust_ta_lib::MaType::Sma),
+        Err(TaError::InputLengthMismatch {
+            expected: 40,
+            found: 39
+        })
+    );
+}
+
+#[test]
+fn sarext_with_default_options_matches_sar() {
+    let close: Vec<f64> = (0..60).map(|i| 10.0 + (i as f64 / 5.0).sin()).collect();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.2).collect();
+    let low: Vec<f64> = close.iter().map(|c| c - 0.2).collect();
+    let (sar, sar_begin) = wrapper::sar(&high, &low, 0.02, 0.2).unwrap();
+    let (sarext, sarext_begin) = wrapper::sarext(&high, &low, &SarExtOptions::default()).unwrap();
+    assert_eq!(sar_begin, sarext_begin);
+    // SAREXT reports short positions as negative values
+    let sarext: Vec<f64> = sarext.iter().map(|v| v.abs()).collect();
+    assert_eq!(sar, sarext);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::close;
+use rust_ta_lib::wrapper;
+use rust_ta_lib::wrapper::SarExtOptions;
+use rust_ta_lib::TaError;
+
+#[test]
+fn periods_outside_of_the_documented_range_are_rejected() {
+    let close = close();
+    let expected = Err(TaError::ParamOutOfRange {
+        name: "period",
+        range: "from 2 to 100000",
+    });
+    assert_eq!(wrapper::rsi(1, &close).map(|_| ()), expected);
+    assert_eq!(wrapper::rsi(100001, &close).map(|_| ()), expected);
+    // would wrap to a negative TA_Integer with an unchecked cast
+    assert_eq!(wrapper::rsi(u32::MAX, &close).map(|_| ()), expected);
+    assert_eq!(wrapper::rsi_lookback(1).map(|_| ()), expected);
+    let mut out = vec![0.0; close.len()];
+    assert_eq!(wrapper::rsi_into(1, &close, &mut out).map(|_| ()), expected);
+    assert!(wrapper::rsi(2, &close).is_ok());
+}
+
+#[test]
+fn real_options_are_checked() {
+    let close = close();
+    assert_eq!(
+        wrapper::mama(&close, 0.995, 0.05).map(|_| ()),
+        Err(TaError::ParamOutOfRange {
+            name: "in_fast_limit",
+            range: "from 0.01 to 0.99"
+        })
+    );
+    assert_eq!(
+        wrapper::t3(&close, 5, f64::NAN).map(|_| ()),
+        Err(TaError::ParamOutOfRange {
+            name: "vfactor",
+            range: "from 0.0 to 1.0"
+        })
+    );
+    let options = SarExtOptions {
+        acceleration_long: -0.02,
+        ..SarExtOptions::default()
+    };
+    assert_eq!(
+        wrapper::sarext(&close, &close, &options).map(|_| ()),
+        Err(TaError::ParamOutOfRange {
+            name: "acceleration_long",
+            range: "from 0.0 to 3e37"
+        })
+    );
+}
+
+#[test]
+fn mismatched_ohlcv_inputs_are_rejected() {
+    let close = close();
+    let short = &close[..90];
+    let expected = Err(TaError::InputLengthMismatch {
+        expected: 300,
+        found: 90,
+    });
+    assert_eq!(
+        wrapper::cdlengulfing(&close, &close, short, &close).map(|_| ()),
+        expected
+    );
+    assert_eq!(
+        wrapper::cci(&close, &close, short, 14).map(|_| ()),
+        expected
+    );
+    assert_eq!(
+        wrapper::accbands(20, &close, short, &close).map(|_| ()),
+        expected
+    );
+}
+
+#[test]
+fn errors_name_the_parameter() {
+    let err = wrapper::sma_lookback(1).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "period is out of range, expected a value from 2 to 100000"
+    );
+    assert_eq!(err.ret_code(), rust_ta_lib::TA_RetCode_TA_BAD_PARAM);
+}
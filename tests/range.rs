@@ -0,0 +1,58 @@
+mod common;
+
+use common::close;
+use rust_ta_lib::wrapper;
+use rust_ta_lib::TaError;
+
+#[test]
+fn range_matches_the_tail_of_a_full_computation() {
+    let close = close();
+    let (full, full_begin) = wrapper::sma(20, &close).unwrap();
+    let (tail, begin) = wrapper::sma_range(20, &close, 250..).unwrap();
+    assert_eq!(begin, 250);
+    assert_eq!(tail.len(), 50);
+    let offset = (begin - full_begin) as usize;
+    for (value, expected) in tail.iter().zip(&full[offset..]) {
+        assert!((value - expected).abs() < 1e-9);
+    }
+
+    let (last, begin) = wrapper::sma_range(20, &close, 299..=299).unwrap();
+    assert_eq!((last.len(), begin), (1, 299));
+}
+
+#[test]
+fn empty_input_gives_empty_output() {
+    let empty: Vec<f64> = Vec::new();
+    assert_eq!(wrapper::sma(20, &empty), Ok((Vec::new(), 0)));
+    assert_eq!(wrapper::rsi(14, &empty), Ok((Vec::new(), 0)));
+    let bands = wrapper::bbands(5, &empty, 2.0, 2.0, rust_ta_lib::MaType::Sma).unwrap();
+    assert!(bands.upper.is_empty() && bands.middle.is_empty() && bands.lower.is_empty());
+    assert_eq!(bands.begin, 0);
+    assert_eq!(
+        wrapper::sma_range(20, &close(), 100..100),
+        Ok((Vec::new(), 0))
+    );
+}
+
+#[test]
+fn out_of_bounds_ranges_are_rejected() {
+    let close = close();
+    assert_eq!(
+        wrapper::sma_range(20, &close, 100..301),
+        Err(TaError::OutOfRangeEndIndex)
+    );
+    assert_eq!(
+        wrapper::sma_range(20, &close, 0..=300),
+        Err(TaError::OutOfRangeEndIndex)
+    );
+    assert_eq!(
+        wrapper::sma_range(20, &close, 301..),
+        Err(TaError::OutOfRangeStartIndex)
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 200..100;
+    assert_eq!(
+        wrapper::sma_range(20, &close, reversed),
+        Err(TaError::OutOfRangeStartIndex)
+    );
+}
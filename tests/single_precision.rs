@@ -0,0 +1,130 @@
+use rust_ta_lib::wrapper;
+
+struct Series {
+    open: Vec<f64>,
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<f64>,
+}
+
+fn series() -> Series {
+    let close: Vec<f64> = (0..120)
+        .map(|i| 10.0 + (i as f64 / 6.0).sin() + i as f64 * 0.01)
+        .collect();
+    let open: Vec<f64> = close.iter().map(|c| c - 0.05).collect();
+    let high: Vec<f64> = close.iter().map(|c| c + 0.2).collect();
+    let low: Vec<f64> = close.iter().map(|c| c - 0.2).collect();
+    let volume: Vec<f64> = (0..120).map(|i| 1000.0 + (i % 7) as f64 * 50.0).collect();
+    Series {
+        open,
+        high,
+        low,
+        close,
+        volume,
+    }
+}
+
+fn single(values: &[f64]) -> Vec<f32> {
+    values.iter().map(|v| *v as f32).collect()
+}
+
+// f32 inputs lose precision, so values only have to agree within a relative tolerance
+fn assert_close(double: &[f64], single: &[f64]) {
+    assert_eq!(double.len(), single.len());
+    for (i, (a, b)) in double.iter().zip(single).enumerate() {
+        assert!(
+            (a - b).abs() <= 1e-4 * a.abs().max(1.0),
+            "{} and {} differ at {}",
+            a,
+            b,
+            i
+        );
+    }
+}
+
+macro_rules! same_values {
+    ($double:expr, $single:expr) => {{
+        let (double, double_begin) = $double.unwrap();
+        let (single, single_begin) = $single.unwrap();
+        assert_eq!(double_begin, single_begin);
+        assert_close(&double, &single);
+    }};
+}
+
+#[test]
+fn single_precision_forms_match_the_double_ones() {
+    let Series {
+        open,
+        high,
+        low,
+        close,
+        volume,
+    } = series();
+    let (s_open, s_high, s_low, s_close, s_volume) = (
+        single(&open),
+        single(&high),
+        single(&low),
+        single(&close),
+        single(&volume),
+    );
+
+    same_values!(
+        wrapper::natr(&high, &low, &close, 14),
+        wrapper::natr(&s_high, &s_low, &s_close, 14)
+    );
+    same_values!(
+        wrapper::cci(&high, &low, &close, 14),
+        wrapper::cci(&s_high, &s_low, &s_close, 14)
+    );
+    same_values!(
+        wrapper::mfi(&high, &low, &close, &volume, 14),
+        wrapper::mfi(&s_high, &s_low, &s_close, &s_volume, 14)
+    );
+    same_values!(
+        wrapper::willr(&high, &low, &close, 14),
+        wrapper::willr(&s_high, &s_low, &s_close, 14)
+    );
+    same_values!(
+        wrapper::adosc(&high, &low, &close, &volume, 3, 10),
+        wrapper::adosc(&s_high, &s_low, &s_close, &s_volume, 3, 10)
+    );
+    same_values!(
+        wrapper::aroonosc(&high, &low, 14),
+        wrapper::aroonosc(&s_high, &s_low, 14)
+    );
+    same_values!(
+        wrapper::apo(&close, 12, 26, rust_ta_lib::MaType::Sma),
+        wrapper::apo(&s_close, 12, 26, rust_ta_lib::MaType::Sma)
+    );
+    same_values!(
+        wrapper::ppo(&close, 12, 26, rust_ta_lib::MaType::Sma),
+        wrapper::ppo(&s_close, 12, 26, rust_ta_lib::MaType::Sma)
+    );
+    same_values!(
+        wrapper::avgprice(&open, &high, &low, &close),
+        wrapper::avgprice(&s_open, &s_high, &s_low, &s_close)
+    );
+    same_values!(
+        wrapper::medprice(&high, &low),
+        wrapper::medprice(&s_high, &s_low)
+    );
+    same_values!(
+        wrapper::typprice(&high, &low, &close),
+        wrapper::typprice(&s_high, &s_low, &s_close)
+    );
+    same_values!(
+        wrapper::wclprice(&high, &low, &close),
+        wrapper::wclprice(&s_high, &s_low, &s_close)
+    );
+    same_values!(
+        wrapper::trange(&high, &low, &close),
+        wrapper::trange(&s_high, &s_low, &s_close)
+    );
+
+    let out = wrapper::stochrsi(&close, 14, 5, 3, rust_ta_lib::MaType::Sma).unwrap();
+    let s_out = wrapper::stochrsi(&s_close, 14, 5, 3, rust_ta_lib::MaType::Sma).unwrap();
+    assert_eq!(out.begin, s_out.begin);
+    assert_close(&out.fast_k, &s_out.fast_k);
+    assert_close(&out.fast_d, &s_out.fast_d);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "serde")]
+
+use rust_ta_lib::stream::{
+    AtrStream, BBandsStream, EmaStream, Hlc, MacdStream, RsiStream, SmaStream, StochStream,
+};
+use rust_ta_lib::MaType;
+
+fn bars() -> Vec<Hlc> {
+    (0..200)
+        .map(|i| {
+            let close = 50.0 + (i as f64 / 8.0).sin() * 4.0 + (i % 3) as f64 * 0.1;
+            Hlc {
+                high: close + 0.4,
+                low: close - 0.3,
+                close,
+            }
+        })
+        .collect()
+}
+
+// Gives the restored stream the type of the saved one
+fn like<S>(_: &S, restored: S) -> S {
+    restored
+}
+
+// Feeds half the bars, checkpoints with `save`/`load`, and checks the resumed stream carries on
+// exactly like the one that never stopped
+macro_rules! resumes {
+    ($stream:expr, $input:expr, $save:expr, $load:expr) => {{
+        let bars = bars();
+        let (first, rest) = bars.split_at(bars.len() / 2);
+        let mut stream = $stream;
+        for bar in first {
+            stream.next($input(bar));
+        }
+        let snapshot = $save(&stream);
+        let mut resumed = like(&stream, $load(&snapshot));
+        for bar in rest {
+            assert_eq!(resumed.next($input(bar)), stream.next($input(bar)));
+        }
+    }};
+}
+
+macro_rules! resumes_in_every_format {
+    ($stream:expr, $input:expr) => {{
+        resumes!(
+            $stream,
+            $input,
+            |stream| serde_json::to_string(stream).unwrap(),
+            |json: &String| serde_json::from_str(json).unwrap()
+        );
+        resumes!(
+            $stream,
+            $input,
+            |stream| rmp_serde::to_vec(stream).unwrap(),
+            |bytes: &Vec<u8>| rmp_serde::from_slice(bytes).unwrap()
+        );
+    }};
+}
+
+#[test]
+fn streams_resume_from_a_snapshot() {
+    let close = |bar: &Hlc| bar.close;
+    let bar = |bar: &Hlc| *bar;
+    resumes_in_every_format!(RsiStream::new(14).unwrap(), close);
+    resumes_in_every_format!(MacdStream::new(12, 26, 9).unwrap(), close);
+    resumes_in_every_format!(BBandsStream::new(20, 2.0, 2.0, MaType::Ema).unwrap(), close);
+    resumes_in_every_format!(AtrStream::new(14).unwrap(), bar);
+    resumes_in_every_format!(
+        StochStream::new(5, 3, MaType::Sma, 3, MaType::Sma).unwrap(),
+        bar
+    );
+}
+
+#[test]
+fn snapshots_are_tagged() {
+    let json = serde_json::to_value(RsiStream::new(14).unwrap()).unwrap();
+    assert_eq!(json["snapshot"], "RsiStream v1");
+
+    // snapshot of another stream
+    let atr = serde_json::to_string(&AtrStream::new(14).unwrap()).unwrap();
+    let err = serde_json::from_str::<RsiStream>(&atr).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("snapshot of AtrStream v1, expected a snapshot of RsiStream v1"));
+
+    // snapshot from a crate with another format
+    let mut old = json.clone();
+    old["snapshot"] = "RsiStream v0".into();
+    let err = serde_json::from_value::<RsiStream>(old).unwrap_err();
+    assert!(err.to_string().contains("snapshot of RsiStream v0"));
+
+    let bytes = rmp_serde::to_vec(&AtrStream::new(14).unwrap()).unwrap();
+    assert!(rmp_serde::from_slice::<RsiStream>(&bytes).is_err());
+}
+
+#[test]
+fn restored_parameters_are_checked() {
+    let mut rsi = serde_json::to_value(RsiStream::new(14).unwrap()).unwrap();
+    rsi["period"] = 0.into();
+    let err = serde_json::from_value::<RsiStream>(rsi).unwrap_err();
+    assert!(err.to_string().contains("period is out of range"));
+
+    let mut sma = serde_json::to_value(SmaStream::new(10).unwrap()).unwrap();
+    sma["period"] = 0.into();
+    assert!(serde_json::from_value::<SmaStream>(sma).is_err());
+
+    let mut atr = serde_json::to_value(AtrStream::new(14).unwrap()).unwrap();
+    atr["period"] = 0.into();
+    assert!(serde_json::from_value::<AtrStream>(atr).is_err());
+
+    // the average has to match the bands
+    let mut bbands =
+        serde_json::to_value(BBandsStream::new(20, 2.0, 2.0, MaType::Sma).unwrap()).unwrap();
+    bbands["period"] = 10.into();
+    assert!(serde_json::from_value::<BBandsStream>(bbands).is_err());
+
+    // a signal line of period 1 is fine inside a MACD, a lone EMA needs 2
+    let macd = serde_json::to_value(MacdStream::new(12, 26, 1).unwrap()).unwrap();
+    let mut ema = macd["signal"].clone();
+    serde_json::from_value::<MacdStream>(macd).unwrap();
+    assert!(serde_json::from_value::<EmaStream>(ema.clone()).is_err());
+    ema["period"] = 2.into();
+    serde_json::from_value::<EmaStream>(ema).unwrap();
+
+    let stoch = StochStream::new(5, 1, MaType::Sma, 3, MaType::Ema).unwrap();
+    let bytes = rmp_serde::to_vec(&stoch).unwrap();
+    rmp_serde::from_slice::<StochStream>(&bytes).unwrap();
+}
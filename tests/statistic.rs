@@ -0,0 +1,29 @@
+use rust_ta_lib::wrapper;
+use rust_ta_lib::TaError;
+
+#[test]
+fn mismatched_series_are_rejected() {
+    let a: Vec<f64> = (0..50).map(|i| i as f64).collect();
+    let b: Vec<f64> = (0..40).map(|i| i as f64 * 2.0).collect();
+    let expected = Err(TaError::InputLengthMismatch {
+        expected: 50,
+        found: 40,
+    });
+    assert_eq!(wrapper::beta(&a, &b, 5), expected);
+    assert_eq!(wrapper::correl(&a, &b, 30), expected);
+
+    let a: Vec<f32> = a.iter().map(|v| *v as f32).collect();
+    let b: Vec<f32> = b.iter().map(|v| *v as f32).collect();
+    assert_eq!(wrapper::beta(&a, &b, 5), expected);
+    assert_eq!(wrapper::correl(&a, &b, 30), expected);
+}
+
+#[test]
+fn equal_series_are_accepted() {
+    let a: Vec<f64> = (0..50).map(|i| (i as f64).sin()).collect();
+    let b: Vec<f64> = a.iter().map(|v| v * 3.0 + 1.0).collect();
+    let (out, begin) = wrapper::correl(&a, &b, 10).unwrap();
+    assert!(begin as usize + out.len() <= a.len());
+    let (out, begin) = wrapper::beta(&a, &b, 5).unwrap();
+    assert!(begin as usize + out.len() <= a.len());
+}
@@ -0,0 +1,227 @@
+use rust_ta_lib::stream::{
+    AtrStream, BBandsStream, EmaStream, Hlc, MacdStream, RsiStream, SmaStream, StochStream,
+};
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{MaType, TaError, UnstablePeriod};
+
+// xorshift, enough to draw many series without a dependency
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u32) -> u32 {
+        (self.next() % bound as u64) as u32
+    }
+
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // random walk with flat stretches, so that zero ranges and equal values show up too
+    fn bars(&mut self) -> Vec<Hlc> {
+        let len = self.below(250) as usize;
+        let mut close = 10.0 + self.unit() * 100.0;
+        (0..len)
+            .map(|_| {
+                if self.below(8) != 0 {
+                    close += (self.unit() - 0.5) * close * 0.05;
+                }
+                let spread = if self.below(10) == 0 {
+                    0.0
+                } else {
+                    self.unit() * close * 0.02
+                };
+                Hlc {
+                    high: close + spread * self.unit(),
+                    low: close - spread * self.unit(),
+                    close,
+                }
+            })
+            .collect()
+    }
+
+    fn ma_type(&mut self) -> MaType {
+        [MaType::Sma, MaType::Ema][self.below(2) as usize]
+    }
+}
+
+fn closes(bars: &[Hlc]) -> Vec<f64> {
+    bars.iter().map(|bar| bar.close).collect()
+}
+
+fn columns(bars: &[Hlc]) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    (
+        bars.iter().map(|bar| bar.high).collect(),
+        bars.iter().map(|bar| bar.low).collect(),
+        closes(bars),
+    )
+}
+
+// Streams hide the first `begin` outputs and must then repeat the batch values bit for bit
+fn check<S>(
+    streamed: &[Option<S>],
+    begin: i32,
+    batch: &[Vec<f64>],
+    values: impl Fn(&S) -> Vec<f64>,
+) {
+    let begin = begin as usize;
+    let batch_len = batch.first().map_or(0, Vec::len);
+    if batch_len == 0 {
+        assert!(streamed.iter().all(Option::is_none));
+        return;
+    }
+    assert!(streamed[..begin].iter().all(Option::is_none));
+    assert_eq!(streamed.len() - begin, batch_len);
+    for (offset, value) in streamed[begin..].iter().enumerate() {
+        let value = values(value.as_ref().expect("a value after the warm-up"));
+        for (column, streamed) in batch.iter().zip(value) {
+            assert_eq!(
+                streamed.to_bits(),
+                column[offset].to_bits(),
+                "output {} differs",
+                begin + offset
+            );
+        }
+    }
+}
+
+// Runs `case` for many random inputs, with and without unstable periods. The guard also keeps
+// the tests of this file from changing the unstable periods under each other.
+fn for_random_bars(seed: u64, case: impl Fn(&mut Random, &[Hlc])) {
+    let mut random = Random(seed);
+    for unstable in [0, 7] {
+        let _guard = UnstablePeriod::scoped_all(unstable).unwrap();
+        for _ in 0..200 {
+            let bars = random.bars();
+            case(&mut random, &bars);
+        }
+    }
+}
+
+#[test]
+fn sma_matches_the_batch_wrapper() {
+    for_random_bars(1, |random, bars| {
+        let period = 2 + random.below(30);
+        let mut stream = SmaStream::new(period).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(bar.close)).collect();
+        let (batch, begin) = wrapper::sma(period, &closes(bars)).unwrap();
+        check(&streamed, begin, &[batch], |value| vec![*value]);
+    });
+}
+
+#[test]
+fn ema_matches_the_batch_wrapper() {
+    for_random_bars(2, |random, bars| {
+        let period = 2 + random.below(30);
+        let mut stream = EmaStream::new(period).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(bar.close)).collect();
+        let (batch, begin) = wrapper::ema(&closes(bars), period).unwrap();
+        check(&streamed, begin, &[batch], |value| vec![*value]);
+    });
+}
+
+#[test]
+fn rsi_matches_the_batch_wrapper() {
+    for_random_bars(3, |random, bars| {
+        let period = 2 + random.below(30);
+        let mut stream = RsiStream::new(period).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(bar.close)).collect();
+        let (batch, begin) = wrapper::rsi(period, &closes(bars)).unwrap();
+        check(&streamed, begin, &[batch], |value| vec![*value]);
+    });
+}
+
+#[test]
+fn macd_matches_the_batch_wrapper() {
+    for_random_bars(4, |random, bars| {
+        let (fast, slow, signal) = (
+            2 + random.below(20),
+            2 + random.below(40),
+            1 + random.below(15),
+        );
+        let mut stream = MacdStream::new(fast, slow, signal).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(bar.close)).collect();
+        let batch = wrapper::macd(fast, slow, signal, &closes(bars)).unwrap();
+        check(
+            &streamed,
+            batch.begin,
+            &[batch.macd, batch.signal, batch.hist],
+            |value| vec![value.macd, value.signal, value.hist],
+        );
+    });
+}
+
+#[test]
+fn atr_matches_the_batch_wrapper() {
+    for_random_bars(5, |random, bars| {
+        let period = 1 + random.below(30);
+        let mut stream = AtrStream::new(period).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(*bar)).collect();
+        let (high, low, close) = columns(bars);
+        let (batch, begin) = wrapper::atr(period, &high, &low, &close).unwrap();
+        check(&streamed, begin, &[batch], |value| vec![*value]);
+    });
+}
+
+#[test]
+fn bbands_matches_the_batch_wrapper() {
+    for_random_bars(6, |random, bars| {
+        let period = 2 + random.below(30);
+        let (up, down) = (random.unit() * 3.0, [1.0, 2.0][random.below(2) as usize]);
+        let ma_type = random.ma_type();
+        let mut stream = BBandsStream::new(period, up, down, ma_type).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(bar.close)).collect();
+        let batch = wrapper::bbands(period, &closes(bars), up, down, ma_type).unwrap();
+        check(
+            &streamed,
+            batch.begin,
+            &[batch.upper, batch.middle, batch.lower],
+            |value| vec![value.upper, value.middle, value.lower],
+        );
+    });
+}
+
+#[test]
+fn stoch_matches_the_batch_wrapper() {
+    for_random_bars(7, |random, bars| {
+        let (fastk, slowk, slowd) = (
+            1 + random.below(20),
+            1 + random.below(8),
+            1 + random.below(8),
+        );
+        let (slowk_ma, slowd_ma) = (random.ma_type(), random.ma_type());
+        let mut stream = StochStream::new(fastk, slowk, slowk_ma, slowd, slowd_ma).unwrap();
+        let streamed: Vec<_> = bars.iter().map(|bar| stream.next(*bar)).collect();
+        let (high, low, close) = columns(bars);
+        let batch =
+            wrapper::stoch(fastk, slowk, slowk_ma, slowd, slowd_ma, &high, &low, &close).unwrap();
+        check(
+            &streamed,
+            batch.begin,
+            &[batch.slow_k, batch.slow_d],
+            |value| vec![value.slow_k, value.slow_d],
+        );
+    });
+}
+
+#[test]
+fn parameters_are_checked_like_the_batch_wrappers() {
+    assert_eq!(
+        SmaStream::new(1).unwrap_err(),
+        wrapper::sma_lookback(1).unwrap_err()
+    );
+    assert_eq!(
+        MacdStream::new(12, 26, 0).unwrap_err(),
+        wrapper::macd_lookback(12, 26, 0).unwrap_err()
+    );
+    assert_eq!(
+        BBandsStream::new(20, 2.0, 2.0, MaType::Kama).unwrap_err(),
+        TaError::NotSupported
+    );
+}
@@ -0,0 +1,58 @@
+use std::thread;
+
+use rust_ta_lib::wrapper;
+use rust_ta_lib::{TaLib, UnstableFunc, UnstablePeriod};
+
+fn c_value(func: UnstableFunc) -> u32 {
+    unsafe { rust_ta_lib::TA_GetUnstablePeriod(func.id()) }
+}
+
+#[test]
+fn guards_restore_the_previous_values() {
+    let outer = UnstablePeriod::scoped(UnstableFunc::Rsi, 10).unwrap();
+    wrapper::rsi(14, &[1.0; 50]).unwrap();
+    assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 10);
+    assert_eq!(c_value(UnstableFunc::Rsi), 10);
+    {
+        let _inner = UnstablePeriod::scoped_all(25).unwrap();
+        assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 25);
+        assert_eq!(UnstablePeriod::get(UnstableFunc::T3), 25);
+        UnstablePeriod::set(UnstableFunc::Kama, 3).unwrap();
+        assert_eq!(UnstablePeriod::get(UnstableFunc::Kama), 3);
+    }
+    assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 10);
+    assert_eq!(UnstablePeriod::get(UnstableFunc::T3), 0);
+    assert_eq!(UnstablePeriod::get(UnstableFunc::Kama), 0);
+    drop(outer);
+    assert_eq!(UnstablePeriod::get(UnstableFunc::Rsi), 0);
+    assert_eq!(c_value(UnstableFunc::Rsi), 0);
+}
+
+#[test]
+fn threads_do_not_clobber_each_other() {
+    thread::scope(|scope| {
+        for period in 1..=8 {
+            scope.spawn(move || {
+                for _ in 0..50 {
+                    let _guard = UnstablePeriod::scoped(UnstableFunc::Atr, period).unwrap();
+                    wrapper::rsi(14, &[1.0; 50]).unwrap();
+                    assert_eq!(UnstablePeriod::get(UnstableFunc::Atr), period);
+                    assert_eq!(c_value(UnstableFunc::Atr), period);
+                }
+            });
+        }
+    });
+    // other tests in this binary override every period for a while, holding a guard waits
+    // until they are done
+    let _guard = UnstablePeriod::scoped(UnstableFunc::Rsi, 0).unwrap();
+    assert_eq!(UnstablePeriod::get(UnstableFunc::Atr), 0);
+}
+
+#[test]
+fn values_survive_a_shutdown() {
+    let _guard = UnstablePeriod::scoped(UnstableFunc::Mama, 40).unwrap();
+    drop(TaLib::init().unwrap());
+    // the next computation initializes the library again
+    wrapper::rsi(14, &[1.0; 50]).unwrap();
+    assert_eq!(c_value(UnstableFunc::Mama), 40);
+}